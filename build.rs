@@ -0,0 +1,42 @@
+//! With the `embedded-inputs` feature, lists the inputs in `src/bin` for
+//! `src/input.rs` to include in the binary, plaintext or encrypted. Empty
+//! inputs of days just scaffolded are left out.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/input_files.rs"]
+mod input_files;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/input_files.rs");
+    if env::var_os("CARGO_FEATURE_EMBEDDED_INPUTS").is_none() {
+        return;
+    }
+
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    // new inputs are picked up, changes to the included ones are tracked by rustc
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut list = String::from("&[\n");
+    for day in 1..=25 {
+        // the file the runtime would read, an encrypted input coming first
+        let path = input_files::input_path(&dir, day);
+        if !fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            continue;
+        }
+        let embedded = if input_files::is_encrypted_path(&path) {
+            format!("Embedded::Encrypted(include_bytes!({:?}))", path)
+        } else {
+            format!("Embedded::Plain(include_str!({:?}))", path)
+        };
+        list.push_str(&format!("    ({}, {}),\n", day, embedded));
+    }
+    list.push(']');
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    fs::write(&out, list)
+        .unwrap_or_else(|error| panic!("cannot write {}: {}", out.display(), error));
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::solution::{Answer, Part};
+
+/// File holding the answers accepted on the puzzle website
+pub const ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Accepted answers by day and part, stored as
+///
+/// ```toml
+/// [day1]
+/// part1 = 54951
+/// part2 = 55218
+/// ```
+#[derive(Debug, Default)]
+pub struct AcceptedAnswers {
+    table: Table,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    /// A day whose value is not a table of answers, by its key
+    NotATable(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(error) => write!(f, "could not access {}: {}", ANSWERS_FILE, error),
+            AnswersError::Toml(error) => write!(f, "invalid {}: {}", ANSWERS_FILE, error),
+            AnswersError::NotATable(key) => {
+                write!(
+                    f,
+                    "invalid {}: {} is not a table of answers",
+                    ANSWERS_FILE, key
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+impl AcceptedAnswers {
+    /// Loads the answers, with none accepted yet if the file does not exist
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(AnswersError::Io(error)),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, AnswersError> {
+        let table: Table = content.parse().map_err(AnswersError::Toml)?;
+        if let Some((key, _)) = table
+            .iter()
+            .find(|(key, value)| day_number(key).is_some() && !value.is_table())
+        {
+            return Err(AnswersError::NotATable(key.clone()));
+        }
+        Ok(Self { table })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.table.to_string()).map_err(AnswersError::Io)
+    }
+
+    /// The accepted answer, written the same way as the [`Answer`] it was accepted from
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        match self.table.get(&day_key(day))?.get(part_key(part))? {
+            Value::Integer(value) => Some(value.to_string()),
+            Value::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn accept(&mut self, day: u8, part: Part, answer: Answer) {
+        // TOML integers are signed 64 bits, bigger answers are kept as strings
+        let value = match answer {
+            Answer::Signed(value) => Value::Integer(value),
+            Answer::Unsigned(value) => i64::try_from(value)
+                .map(Value::Integer)
+                .unwrap_or_else(|_| Value::String(value.to_string())),
+        };
+
+        self.table
+            .entry(day_key(day))
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("parse only accepts days that are tables of answers")
+            .insert(part_key(part).to_owned(), value);
+
+        let mut days: Vec<(String, Value)> = mem::take(&mut self.table).into_iter().collect();
+        days.sort_by_key(|(key, _)| day_number(key));
+        self.table = days.into_iter().collect();
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{}", day)
+}
+
+fn day_number(key: &str) -> Option<u8> {
+    key.strip_prefix("day")?.parse().ok()
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_accepted_answer() {
+        let answers = AcceptedAnswers::parse("[day9]\npart2 = -3\n").unwrap();
+
+        assert_eq!(Some("-3".to_owned()), answers.get(9, Part::Two));
+        assert_eq!(None, answers.get(9, Part::One));
+        assert_eq!(None, answers.get(1, Part::One));
+    }
+
+    #[test]
+    fn test_days_must_be_tables() {
+        let error = AcceptedAnswers::parse("day3 = 5\n").unwrap_err();
+
+        assert!(matches!(&error, AnswersError::NotATable(key) if key == "day3"));
+        assert!(error
+            .to_string()
+            .ends_with("day3 is not a table of answers"));
+    }
+
+    #[test]
+    fn test_accept_keeps_days_in_order() {
+        let mut answers = AcceptedAnswers::parse("[day10]\npart1 = 1\n").unwrap();
+
+        answers.accept(2, Part::One, Answer::Unsigned(u64::MAX));
+        answers.accept(10, Part::Two, Answer::Signed(-2));
+
+        assert_eq!(
+            "[day2]\npart1 = \"18446744073709551615\"\n\n[day10]\npart1 = 1\npart2 = -2\n",
+            answers.table.to_string()
+        );
+        assert_eq!(Some(u64::MAX.to_string()), answers.get(2, Part::One));
+    }
+}
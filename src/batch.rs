@@ -0,0 +1,283 @@
+//! Runs a day on every input of a directory, like the inputs of everyone on
+//! the team, since a bug often only shows on some of them. Each input gets a
+//! row with its answers, timings and failures.
+//!
+//! An input `<name>.txt`, or `<name>.txt.enc` when encrypted, can come with a
+//! `<name>.toml` sidecar holding its expected answers, as the examples do.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::days::Day;
+use crate::encryption;
+use crate::fixtures;
+use crate::input::{self, InputArg};
+use crate::report::{self, PartReport, Status};
+use crate::solution::Part;
+use crate::timing;
+
+/// Outcome of running a day on one input
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputRow {
+    pub path: PathBuf,
+    pub reports: Vec<PartReport>,
+    /// None without a sidecar
+    pub expected: Option<Vec<(Part, String)>>,
+    /// Answers differing from the expected ones, or why those could not be read
+    pub mismatches: Vec<String>,
+}
+
+impl InputRow {
+    pub fn failed(&self) -> bool {
+        !self.mismatches.is_empty() || self.reports.iter().any(|report| report.status.is_failure())
+    }
+
+    /// What went wrong, or whether the answers were checked
+    fn outcome(&self) -> String {
+        let mut failures: Vec<String> = Vec::new();
+        // the input and its parsing fail every part the same way
+        if let Some(report) = self
+            .reports
+            .iter()
+            .find(|report| matches!(report.status, Status::InputError | Status::ParseError))
+        {
+            failures.push(format!(
+                "{}: {}",
+                report.status,
+                report.error.as_deref().unwrap_or_default()
+            ));
+        } else {
+            failures.extend(
+                self.reports
+                    .iter()
+                    .filter(|report| report.status.is_failure())
+                    .map(|report| {
+                        format!(
+                            "part {} {}: {}",
+                            report.part,
+                            report.status,
+                            report.error.as_deref().unwrap_or_default()
+                        )
+                    }),
+            );
+        }
+        failures.extend(self.mismatches.iter().cloned());
+
+        match (failures.is_empty(), &self.expected) {
+            (false, _) => failures.join("; "),
+            (true, Some(_)) => "ok".to_owned(),
+            (true, None) => "no expected answers".to_owned(),
+        }
+    }
+}
+
+/// The inputs in `dir`, plaintext or encrypted, sorted by name
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths: Vec<PathBuf> = dir
+        .read_dir()
+        .and_then(|entries| entries.map(|entry| Ok(entry?.path())).collect())
+        .map_err(|error| format!("{}: {}", dir.display(), error))?;
+    paths.retain(|path| name_of(path).is_some());
+    paths.sort();
+    Ok(paths)
+}
+
+/// Name of the input at `path`, without its extensions
+fn name_of(path: &Path) -> Option<&str> {
+    let file_name = path.file_name()?.to_str()?;
+    let file_name = file_name
+        .strip_suffix(&format!(".{}", encryption::EXTENSION))
+        .unwrap_or(file_name);
+    file_name.strip_suffix(".txt")
+}
+
+/// Where the expected answers of the input at `path` are
+pub fn sidecar_path(path: &Path) -> Option<PathBuf> {
+    Some(path.with_file_name(format!("{}.toml", name_of(path)?)))
+}
+
+/// Answers `parts` of `day` for the input at `path`, checking them against its
+/// sidecar if there is one
+pub fn run_input(day: &Day, parts: &[Part], path: &Path) -> InputRow {
+    let input = input::load(day.number, Some(&InputArg::Path(path.to_owned())));
+    let source = input.as_ref().ok().map(|input| input.source.to_string());
+    // a panic while parsing only fails this input
+    let reports = panic::catch_unwind(AssertUnwindSafe(|| report::run(day, parts, input)))
+        .unwrap_or_else(|payload| {
+            let message = report::panic_message(payload.as_ref());
+            parts
+                .iter()
+                .map(|part| PartReport {
+                    day: day.number,
+                    part: *part,
+                    status: Status::Panicked,
+                    answer: None,
+                    parse_time: None,
+                    solve_time: None,
+                    error: Some(message.clone()),
+                    source: source.clone(),
+                })
+                .collect()
+        });
+
+    let sidecar = sidecar_path(path).filter(|sidecar| sidecar.exists());
+    let (expected, mismatches) = match sidecar.map(|sidecar| fixtures::load_expected(&sidecar)) {
+        None => (None, Vec::new()),
+        Some(Err(error)) => (None, vec![error]),
+        Some(Ok(expected)) => {
+            let mismatches = mismatches(day, &reports, &expected);
+            (Some(expected), mismatches)
+        }
+    };
+
+    InputRow {
+        path: path.to_owned(),
+        reports,
+        expected,
+        mismatches,
+    }
+}
+
+/// Expected answers the reports differ from, leaving out the parts known to be
+/// broken and those that failed anyway
+fn mismatches(day: &Day, reports: &[PartReport], expected: &[(Part, String)]) -> Vec<String> {
+    expected
+        .iter()
+        .filter(|(part, _)| !day.broken_parts.contains(part))
+        .filter_map(|(part, expected)| {
+            let answer = reports.iter().find(|report| report.part == *part)?.answer?;
+            (answer.to_string() != *expected)
+                .then(|| format!("part {}: expected {}, got {}", part, expected, answer))
+        })
+        .collect()
+}
+
+/// Runs `day` on every input of `dir`
+pub fn run_dir(day: &Day, parts: &[Part], dir: &Path) -> Result<Vec<InputRow>, String> {
+    Ok(inputs(dir)?
+        .iter()
+        .map(|path| run_input(day, parts, path))
+        .collect())
+}
+
+/// Renders a row per input with the time taken by parsing, the answer and time
+/// of each part, then whether all went well
+pub fn table(parts: &[Part], rows: &[InputRow]) -> String {
+    let mut header = vec!["input".to_owned(), "parse".to_owned()];
+    header.extend(parts.iter().map(|part| format!("part {}", part)));
+    header.push("result".to_owned());
+
+    let mut lines = vec![header];
+    for row in rows {
+        let name = row.path.file_name().map_or_else(
+            || row.path.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        let parse_time = row
+            .reports
+            .iter()
+            .find_map(|report| report.parse_time)
+            .map_or("-".to_owned(), timing::format_duration);
+        let mut line = vec![name, parse_time];
+        line.extend(parts.iter().map(|part| {
+            match row.reports.iter().find(|report| report.part == *part) {
+                Some(PartReport {
+                    answer: Some(answer),
+                    solve_time: Some(time),
+                    ..
+                }) => format!("{} ({})", answer, timing::format_duration(*time)),
+                Some(report) => report.status.to_string(),
+                None => "-".to_owned(),
+            }
+        }));
+        line.push(row.outcome());
+        lines.push(line);
+    }
+    timing::align(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use std::env;
+    use std::fs;
+
+    const EXAMPLE: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    /// A directory with the inputs and sidecars given by name
+    fn inputs_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (name, content) in files {
+            fs::write(dir.join(name), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_inputs_and_sidecars() {
+        let dir = inputs_dir(
+            "aoc2023-test-batch-inputs",
+            &[
+                ("bob.txt", ""),
+                ("alice.txt.enc", ""),
+                ("alice.toml", ""),
+                ("notes.md", ""),
+            ],
+        );
+
+        let inputs = inputs(&dir).unwrap();
+
+        assert_eq!(vec![dir.join("alice.txt.enc"), dir.join("bob.txt")], inputs);
+        assert_eq!(Some(dir.join("alice.toml")), sidecar_path(&inputs[0]));
+        assert_eq!(Some(dir.join("bob.toml")), sidecar_path(&inputs[1]));
+    }
+
+    #[test]
+    fn test_a_row_per_input() {
+        let dir = inputs_dir(
+            "aoc2023-test-batch-rows",
+            &[
+                ("alice.txt", EXAMPLE),
+                ("alice.toml", "part1 = 23\npart2 = 4\n"),
+                ("bob.txt", EXAMPLE),
+                ("bob.toml", "part2 = 5\n"),
+                ("carol.txt", "..F7\n.S-J\n"),
+                ("dave.txt", "no start\n"),
+            ],
+        );
+        let day = days::find(10).unwrap();
+
+        let rows = run_dir(day, &Part::ALL, &dir).unwrap();
+
+        let outcomes: Vec<String> = rows.iter().map(InputRow::outcome).collect();
+        assert_eq!("ok", outcomes[0]);
+        assert_eq!("part 2: expected 5, got 4", outcomes[1]);
+        assert_eq!("no expected answers", outcomes[2]);
+        assert!(outcomes[3].starts_with("parse_error: "), "{}", outcomes[3]);
+        assert_eq!(
+            vec![false, true, false, true],
+            rows.iter().map(InputRow::failed).collect::<Vec<_>>()
+        );
+
+        let table = table(&Part::ALL, &rows);
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("input     | parse"));
+        assert!(lines[1].starts_with("alice.txt | "));
+        assert!(lines[1].contains("| 23 ("));
+        let cells: Vec<&str> = lines[4].split(" | ").map(str::trim).collect();
+        assert_eq!(["dave.txt", "-", "parse_error", "parse_error"], cells[..4]);
+        assert!(cells[4].starts_with("parse_error: day10 input, line 1"));
+    }
+}
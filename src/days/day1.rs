@@ -0,0 +1,97 @@
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution, SolveError};
+
+const DIGITS: &[&str] = &[
+    "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Model = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for line in lines {
+            let first_digit = line.chars().find_map(|char| char.to_digit(10)).unwrap();
+            let second_digit = line
+                .chars()
+                .rev()
+                .find_map(|char| char.to_digit(10))
+                .unwrap();
+            sum += first_digit * 10 + second_digit;
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, SolveError> {
+        Ok(lines
+            .iter()
+            .map(|line| line_to_number(line))
+            .sum::<u32>()
+            .into())
+    }
+}
+
+pub fn line_to_number(line: &str) -> u32 {
+    let (first_digit, _) = DIGITS
+        .iter()
+        .filter_map(|digit| line.find(digit).map(|pos| (digit, pos)))
+        .min_by_key(|&(_digit, pos)| pos)
+        .unwrap();
+
+    let (last_digit, _) = DIGITS
+        .iter()
+        .filter_map(|digit| line.rfind(digit).map(|pos| (digit, pos)))
+        .max_by_key(|&(_digit, pos)| pos)
+        .unwrap();
+
+    parse_digit(first_digit) * 10 + parse_digit(last_digit)
+}
+
+fn parse_digit(digit: &str) -> u32 {
+    if let Ok(value) = digit.parse() {
+        return value;
+    }
+
+    match digit {
+        "1" => 1,
+        "2" => 2,
+        "3" => 3,
+        "4" => 4,
+        "5" => 5,
+        "6" => 6,
+        "7" => 7,
+        "8" => 8,
+        "9" => 9,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => panic!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_overlapping_digits() {
+        let example_input = r#"eighthree"#;
+
+        assert_eq!(83, line_to_number(example_input));
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::Grid;
+use crate::parse::{ParseError, Parser};
+use crate::point::{Direction, Point};
+use crate::render::{self, Colour, Picture};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Model = PipesGraph;
+
+    fn parse(input: &str) -> Result<PipesGraph, ParseError> {
+        PipesGraph::parse(input)
+    }
+
+    fn part1(graph: &PipesGraph) -> Result<Answer, SolveError> {
+        Ok(graph.max_distance_in_loop().into())
+    }
+
+    fn part2(graph: &PipesGraph) -> Result<Answer, SolveError> {
+        Ok(graph.points_within_loop().into())
+    }
+}
+
+#[derive(Debug)]
+pub struct PipesGraph {
+    adj_list: HashMap<Point, Vec<Point>>,
+    start: Point,
+    letters: Grid<char>,
+}
+
+impl PipesGraph {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day10::DAY, input);
+        let letters = Grid::parse(Day10::DAY, input, "a pipe, '.' or 'S'", |c| {
+            "|-LJ7F.S".contains(c).then_some(c)
+        })?;
+        let mut adj_list: HashMap<Point, Vec<Point>> = HashMap::new();
+        let mut start = None;
+
+        for (position, char) in letters.iter() {
+            let loc = Point::from(position);
+            let adjacents = match char {
+                '|' => [Direction::North, Direction::South],
+                '-' => [Direction::West, Direction::East],
+                'L' => [Direction::North, Direction::East],
+                'J' => [Direction::North, Direction::West],
+                '7' => [Direction::South, Direction::West],
+                'F' => [Direction::South, Direction::East],
+                'S' => {
+                    start = Some(loc);
+                    continue;
+                }
+                _ => {
+                    adj_list.insert(loc, vec![]);
+                    continue;
+                }
+            };
+
+            let adjacents = adjacents.map(|direction| letters.step(loc, direction));
+            adj_list.insert(loc, adjacents.into_iter().flatten().collect());
+        }
+
+        let start = start.ok_or_else(|| parser.error(input, "a start tile 'S'"))?;
+        let start_neighbors = letters
+            .neighbours8(start.position().unwrap())
+            .map(Point::from)
+            .filter(|neighbor| adj_list[neighbor].contains(&start))
+            .collect();
+        adj_list.insert(start, start_neighbors);
+
+        Ok(Self {
+            adj_list,
+            start,
+            letters,
+        })
+    }
+
+    pub fn max_distance_in_loop(&self) -> u64 {
+        self.find_loop().len() as u64 / 2
+    }
+
+    pub fn find_loop(&self) -> HashSet<Point> {
+        self.find_loop_with_trail(&mut Vec::new())
+    }
+
+    /// The loop, recording in `trail` the locations in the order the search
+    /// visits them, from the start
+    pub fn find_loop_with_trail(&self, trail: &mut Vec<Point>) -> HashSet<Point> {
+        let mut visited = HashSet::new();
+        visited.insert(self.start);
+        trail.push(self.start);
+
+        self.adj_list
+            .get(&self.start)
+            .unwrap()
+            .iter()
+            .find(|start_neighbor| !self.adj_list.get(start_neighbor).unwrap().is_empty())
+            .map(|start_neighbor| self.follow_loop(*start_neighbor, visited, trail))
+            .unwrap()
+    }
+
+    /// Follows the pipes from `first_location`, next to the start, until back
+    /// on a visited location, giving all those visited or none at a dead end.
+    /// Iterative, since a loop can be far longer than the stack is deep
+    fn follow_loop(
+        &self,
+        first_location: Point,
+        mut visited: HashSet<Point>,
+        trail: &mut Vec<Point>,
+    ) -> HashSet<Point> {
+        let mut previous_location = self.start;
+        let mut current_location = first_location;
+
+        while visited.insert(current_location) {
+            trail.push(current_location);
+
+            let maybe_next_location = self
+                .adj_list
+                .get(&current_location)
+                .unwrap()
+                .iter()
+                .find(|loc| **loc != previous_location);
+
+            match maybe_next_location {
+                Some(next_location) => {
+                    previous_location = current_location;
+                    current_location = *next_location;
+                }
+                None => return HashSet::new(),
+            }
+        }
+        visited
+    }
+
+    pub fn print_subset(
+        &self,
+        loop_points: &HashSet<Point>,
+        points_to_highlight: &HashSet<Point>,
+        fancy: bool,
+    ) {
+        print!(
+            "{}",
+            self.picture(loop_points, points_to_highlight, fancy)
+                .render(false)
+        );
+    }
+
+    /// The pipes of the loop, drawn with box characters when `fancy`, with
+    /// the points to highlight as '*', or '?' for those on the loop
+    pub fn picture(
+        &self,
+        loop_points: &HashSet<Point>,
+        points_to_highlight: &HashSet<Point>,
+        fancy: bool,
+    ) -> Picture {
+        self.pipes_picture(loop_points, fancy)
+            .layer(
+                "highlighted",
+                Colour::Red,
+                Some('*'),
+                points_to_highlight.iter().copied(),
+            )
+            .layer(
+                "highlighted on the loop",
+                Colour::Yellow,
+                Some('?'),
+                points_to_highlight.intersection(loop_points).copied(),
+            )
+    }
+
+    /// The pipes of the loop alone, drawn with box characters when `fancy`
+    pub fn pipes_picture(&self, loop_points: &HashSet<Point>, fancy: bool) -> Picture {
+        let mut pipes = Grid::new(self.letters.width(), self.letters.height(), '.');
+        for (position, letter) in self.letters.iter() {
+            if !loop_points.contains(&Point::from(position)) {
+                continue;
+            }
+            pipes[position] = match letter {
+                'L' if fancy => '└',
+                'J' if fancy => '┘',
+                '-' if fancy => '─',
+                '|' if fancy => '│',
+                'F' if fancy => '┌',
+                '7' if fancy => '┐',
+                other => *other,
+            };
+        }
+
+        Picture::new(pipes).layer("loop", Colour::Green, None, loop_points.iter().copied())
+    }
+
+    pub fn points_within_loop(&self) -> u64 {
+        let loop_members = self.find_loop();
+        let mut sorted_loop_members: Vec<Point> = loop_members.iter().copied().collect();
+        sorted_loop_members.sort_by_key(|point| (point.x, point.y));
+
+        let mut count = 0;
+        let mut points_inside = HashSet::new();
+
+        for point in self.adj_list.keys() {
+            if loop_members.contains(point) {
+                continue;
+            }
+
+            let x_barriers_left: Vec<String> = sorted_loop_members
+                .iter()
+                .filter(|p| point.x < p.x)
+                .filter(|p| point.y == p.y)
+                .map(|p| self.letters[*p])
+                .filter(|c| ['|', 'S', '7', 'F', 'J', 'L'].contains(c))
+                .map(|c| c.to_string())
+                .collect();
+
+            let x_barriers_left = x_barriers_left
+                .join("")
+                .replace("FJ", "|")
+                .replace("L7", "|");
+
+            let x_barriers_right: Vec<String> = sorted_loop_members
+                .iter()
+                .filter(|p| point.x < p.x)
+                .filter(|p| point.y == p.y)
+                .map(|p| self.letters[*p])
+                .filter(|c| ['|', 'S', '7', 'F', 'J', 'L'].contains(c))
+                .map(|c| c.to_string())
+                .collect();
+
+            let x_barriers_right = x_barriers_right
+                .join("")
+                .replace("FJ", "|")
+                .replace("L7", "|");
+
+            let y_barriers_top: Vec<String> = sorted_loop_members
+                .iter()
+                .filter(|p| point.y > p.y)
+                .filter(|p| point.x == p.x)
+                .map(|p| self.letters[*p])
+                .filter(|c| ['-', 'S', '7', 'F', 'J', 'L'].contains(c))
+                .map(|c| c.to_string())
+                .collect();
+
+            let y_barriers_top = y_barriers_top
+                .join("")
+                .replace("7L", "-")
+                .replace("FJ", "-");
+
+            let y_barriers_bottom: Vec<String> = sorted_loop_members
+                .iter()
+                .filter(|p| point.y < p.y)
+                .filter(|p| point.x == p.x)
+                .map(|p| self.letters[*p])
+                .filter(|c| ['-', 'S', '7', 'F', 'J', 'L'].contains(c))
+                .map(|c| c.to_string())
+                .collect();
+
+            let y_barriers_bottom = y_barriers_bottom
+                .join("")
+                .replace("7L", "-")
+                .replace("FJ", "-");
+
+            // on border
+            if (x_barriers_left.is_empty())
+                || (x_barriers_right.is_empty())
+                || (y_barriers_bottom.is_empty())
+                || (y_barriers_top.is_empty())
+            {
+                continue;
+            }
+
+            let is_inside = !(x_barriers_left.len().is_multiple_of(2)
+                && x_barriers_right.len().is_multiple_of(2)
+                && y_barriers_bottom.len().is_multiple_of(2)
+                && y_barriers_top.len().is_multiple_of(2));
+
+            if is_inside {
+                points_inside.insert(*point);
+                count += 1;
+                continue;
+            }
+        }
+        render::trace("day10: points within the loop", || {
+            self.picture(&loop_members, &points_inside, true)
+        });
+        count
+    }
+
+    /// Slow reference for `points_within_loop`: draws the loop three times
+    /// bigger, so that the gaps between pipes are cells too, and floods the
+    /// outside of it
+    pub fn points_within_loop_brute_force(&self) -> u64 {
+        let loop_points = self.find_loop();
+        let scaled = |point: Point| Point::new(3 * point.x + 1, 3 * point.y + 1);
+        let mut walls = Grid::new(3 * self.letters.width(), 3 * self.letters.height(), false);
+        for point in &loop_points {
+            walls[scaled(*point).position().unwrap()] = true;
+            for next in self.adj_list[point]
+                .iter()
+                .filter(|next| loop_points.contains(next))
+            {
+                let gap = scaled(*point) + (*next - *point);
+                walls[gap.position().unwrap()] = true;
+            }
+        }
+
+        // no pipe reaches the outer third of the cells on the edges
+        let mut outside = Grid::new(walls.width(), walls.height(), false);
+        let mut to_visit = vec![(0, 0)];
+        while let Some(position) = to_visit.pop() {
+            if walls[position] || outside[position] {
+                continue;
+            }
+            outside[position] = true;
+            to_visit.extend(walls.neighbours4(position));
+        }
+
+        self.letters
+            .positions()
+            .map(Point::from)
+            .filter(|point| !loop_points.contains(point) && !outside[scaled(*point)])
+            .count() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+    use std::thread;
+
+    #[test]
+    fn test_long_loop_on_a_default_stack() {
+        // longer than the loops of the real inputs
+        let input = generate::input(10, 7, 200).unwrap();
+        let graph = PipesGraph::parse(&input).unwrap();
+
+        let distance = thread::spawn(move || graph.max_distance_in_loop()).join();
+
+        assert_eq!(6278, distance.unwrap());
+    }
+}
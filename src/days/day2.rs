@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Model = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        Parser::new(Self::DAY, input).lines(Game::parse)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<Answer, SolveError> {
+        Ok(games
+            .iter()
+            .filter(|game| {
+                game.is_possible(Cubes {
+                    red: 12,
+                    green: 13,
+                    blue: 14,
+                })
+            })
+            .map(|game| game.id)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<Answer, SolveError> {
+        Ok(games
+            .iter()
+            .map(|game| game.min_set_of_cubes())
+            .map(|cubes| cubes.power())
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    cubes_revealed: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn parse(input: &str) -> Result<Game, ParseError> {
+        let parser = Parser::new(Day2::DAY, input);
+        let (header, reveals) = parser.split_once(input, ':')?;
+        let id = parser.number(parser.strip_prefix(header, "Game ")?)?;
+        let cubes_revealed = reveals
+            .split(';')
+            .map(|reveal| Cubes::parse(reveal).map_err(|error| error.within(input, reveal)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, cubes_revealed })
+    }
+
+    pub fn is_possible(&self, cubes: Cubes) -> bool {
+        self.cubes_revealed
+            .iter()
+            .all(|cr| cr.red <= cubes.red && cr.green <= cubes.green && cr.blue <= cubes.blue)
+    }
+
+    pub fn min_set_of_cubes(&self) -> Cubes {
+        Cubes {
+            blue: self.cubes_revealed.iter().map(|cr| cr.blue).max().unwrap(),
+            green: self.cubes_revealed.iter().map(|cr| cr.green).max().unwrap(),
+            red: self.cubes_revealed.iter().map(|cr| cr.red).max().unwrap(),
+        }
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, cubes) in self.cubes_revealed.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{}", cubes)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Debug, PartialEq)]
+pub struct Cubes {
+    blue: u32,
+    green: u32,
+    red: u32,
+}
+
+impl Cubes {
+    pub fn parse(input: &str) -> Result<Cubes, ParseError> {
+        let parser = Parser::new(Day2::DAY, input);
+        let mut result = Cubes::default();
+        for extraction in input.split(',') {
+            let (count, color) = parser.split_once(extraction.trim_start(), ' ')?;
+            let count: u32 = parser.number(count)?;
+            match color.trim_end() {
+                "blue" => result.blue += count,
+                "red" => result.red += count,
+                "green" => result.green += count,
+                _ => return Err(parser.error(color, "a color")),
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colors = [
+            (self.red, "red"),
+            (self.green, "green"),
+            (self.blue, "blue"),
+        ];
+        let shown: Vec<String> = colors
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, color)| format!("{} {}", count, color))
+            .collect();
+
+        if shown.is_empty() {
+            // a reveal needs at least one color to be parsed back
+            write!(f, "0 red")
+        } else {
+            write!(f, "{}", shown.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn cubes() -> impl Strategy<Value = Cubes> {
+        (0..20_u32, 0..20_u32, 0..20_u32).prop_map(|(blue, green, red)| Cubes { blue, green, red })
+    }
+
+    fn game() -> impl Strategy<Value = Game> {
+        (1..1000_u32, prop::collection::vec(cubes(), 1..6))
+            .prop_map(|(id, cubes_revealed)| Game { id, cubes_revealed })
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(game in game()) {
+            let printed = game.to_string();
+            prop_assert_eq!(Ok(game), Game::parse(&printed));
+        }
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+
+        assert_eq!(
+            Game {
+                id: 1,
+                cubes_revealed: vec![
+                    Cubes {
+                        blue: 3,
+                        red: 4,
+                        green: 0
+                    },
+                    Cubes {
+                        red: 1,
+                        green: 2,
+                        blue: 6
+                    },
+                    Cubes {
+                        green: 2,
+                        red: 0,
+                        blue: 0
+                    }
+                ]
+            },
+            Game::parse(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "Game 1: 3 blue\nGame 2: 1 blue, 2 purple";
+
+        let error = Day2::parse(input).unwrap_err();
+
+        assert_eq!((2, 19), (error.line, error.column));
+        assert_eq!("a color", error.expected);
+    }
+}
@@ -0,0 +1,125 @@
+use std::ops::{Mul, RangeInclusive};
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Model = EngineSchematic;
+
+    fn parse(input: &str) -> Result<EngineSchematic, ParseError> {
+        EngineSchematic::parse(input)
+    }
+
+    fn part1(schematic: &EngineSchematic) -> Result<Answer, SolveError> {
+        Ok(schematic
+            .engine_parts()
+            .iter()
+            .map(|number| number.value)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(schematic: &EngineSchematic) -> Result<Answer, SolveError> {
+        Ok(schematic.gears().iter().sum::<u32>().into())
+    }
+}
+
+#[derive(Debug)]
+pub struct EngineSchematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+impl EngineSchematic {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(Day3::DAY, input, "a character", Some)?;
+        let mut numbers: Vec<Number> = Vec::new();
+
+        for ((x, y), c) in grid.iter() {
+            let Some(digit) = c.to_digit(10) else {
+                continue;
+            };
+            if let Some(last_num) = numbers.last_mut() {
+                // new digit for existing number
+                if last_num.coord_y == y && *last_num.coord_x.end() + 1 == x {
+                    last_num.value = last_num.value * 10 + digit;
+                    last_num.coord_x = *last_num.coord_x.start()..=x;
+                    continue;
+                }
+            }
+            // new number
+            numbers.push(Number {
+                value: digit,
+                coord_x: (x..=x),
+                coord_y: y,
+            })
+        }
+
+        Ok(EngineSchematic { grid, numbers })
+    }
+
+    pub fn engine_parts(&self) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|number| {
+                number.coord_x.clone().any(|x| {
+                    self.grid
+                        .neighbours8((x, number.coord_y))
+                        .any(|position| is_symbol(self.grid[position]))
+                })
+            })
+            .collect()
+    }
+
+    pub fn gears(&self) -> Vec<u32> {
+        self.grid
+            .iter()
+            .filter(|(_, c)| **c == '*')
+            .map(|((x, y), _)| self.numbers_around_symbol_at(x, y))
+            .filter_map(|numbers: Vec<&Number>| {
+                if numbers.len() >= 2 {
+                    let gear_ratio = numbers.iter().map(|number| number.value).reduce(Mul::mul);
+                    Some(gear_ratio.unwrap())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn numbers_around_symbol_at(&self, coord_x: usize, coord_y: usize) -> Vec<&Number> {
+        self.numbers
+            .iter()
+            .filter(|number| number.is_close_to(coord_x, coord_y))
+            .collect()
+    }
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+#[derive(Debug)]
+pub struct Number {
+    value: u32,
+    coord_x: RangeInclusive<usize>,
+    coord_y: usize,
+}
+
+impl Number {
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn is_close_to(&self, coord_x: usize, coord_y: usize) -> bool {
+        let x_range = (self.coord_x.start().saturating_sub(1))..=(self.coord_x.end() + 1);
+        let y_range = (self.coord_y.saturating_sub(1))..=(self.coord_y + 1);
+
+        x_range.contains(&coord_x) && y_range.contains(&coord_y)
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Model = Cards;
+
+    fn parse(input: &str) -> Result<Cards, ParseError> {
+        Cards::parse(input)
+    }
+
+    fn part1(cards: &Cards) -> Result<Answer, SolveError> {
+        Ok(cards
+            .cards
+            .iter()
+            .map(Card::winning_numbers_count)
+            .filter(|count| *count > 0)
+            .map(|count| 2_u32.pow(count as u32 - 1))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(cards: &Cards) -> Result<Answer, SolveError> {
+        Ok((cards.sum_of_winning_card_instances(0, cards.cards.len() - 1) - 1).into())
+    }
+}
+
+pub struct Cards {
+    pub cards: Vec<Card>,
+}
+
+impl Cards {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            cards: Parser::new(Day4::DAY, input).lines(Card::parse)?,
+        })
+    }
+
+    pub fn sum_of_winning_card_instances(&self, from_index: usize, to_index: usize) -> u32 {
+        let mut res = 0;
+        for i in from_index..=to_index {
+            let card = self.cards.get(i).unwrap();
+            let cards_won_count = card.winning_numbers_count();
+            res += self.sum_of_winning_card_instances(i + 1, i + cards_won_count);
+        }
+        1 + res
+    }
+
+    /// Instances of each card as the copies cascade down: before any card is
+    /// scratched, then after each card in turn adds one copy of the cards it
+    /// wins per instance of itself
+    pub fn instances_after_each_card(&self) -> Vec<Vec<u32>> {
+        let mut instances = vec![1; self.cards.len()];
+        let mut history = vec![instances.clone()];
+        for (i, card) in self.cards.iter().enumerate() {
+            let copies = instances[i];
+            let won_end = (i + 1 + card.winning_numbers_count()).min(self.cards.len());
+            for won in &mut instances[i + 1..won_end] {
+                *won += copies;
+            }
+            history.push(instances.clone());
+        }
+        history
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    id: u32,
+    winning: HashSet<u32>,
+    numbers: HashSet<u32>,
+    winning_numbers_count: usize,
+}
+
+impl Card {
+    pub fn new(id: u32, winning: HashSet<u32>, numbers: HashSet<u32>) -> Self {
+        let winning_numbers_count = winning.intersection(&numbers).count();
+        Self {
+            id,
+            winning,
+            numbers,
+            winning_numbers_count,
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day4::DAY, input);
+        let (header, all_numbers) = parser.split_once(input, ':')?;
+        let (winning, numbers) = parser.split_once(all_numbers, '|')?;
+
+        let id = parser.number(parser.strip_prefix(header, "Card ")?)?;
+        let winning = parser.numbers(winning)?;
+        let numbers = parser.numbers(numbers)?;
+
+        Ok(Self::new(id, winning, numbers))
+    }
+
+    pub fn winning_numbers_count(&self) -> usize {
+        self.winning_numbers_count
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sorted = |numbers: &HashSet<u32>| {
+            let mut numbers: Vec<u32> = numbers.iter().copied().collect();
+            numbers.sort();
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        write!(
+            f,
+            "Card {:>3}: {} | {}",
+            self.id,
+            sorted(&self.winning),
+            sorted(&self.numbers)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn card() -> impl Strategy<Value = Card> {
+        let numbers = || prop::collection::hash_set(1..100_u32, 0..10);
+        (1..1000_u32, numbers(), numbers())
+            .prop_map(|(id, winning, numbers)| Card::new(id, winning, numbers))
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(card in card()) {
+            let printed = card.to_string();
+            prop_assert_eq!(Ok(card), Card::parse(&printed));
+        }
+    }
+
+    #[test]
+    fn test_parse_card() {
+        let card_input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+
+        assert_eq!(
+            Card {
+                id: 1,
+                winning: HashSet::from_iter(vec![41, 48, 83, 86, 17]),
+                numbers: HashSet::from_iter(vec![83, 86, 6, 31, 17, 9, 48, 53]),
+                winning_numbers_count: 4
+            },
+            Card::parse(card_input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_instances_after_each_card() {
+        let cards = Cards::parse(include_str!("../../examples/day4/example1.txt")).unwrap();
+
+        let history = cards.instances_after_each_card();
+
+        assert_eq!(7, history.len());
+        assert_eq!(vec![1; 6], history[0]);
+        assert_eq!(vec![1, 2, 2, 2, 2, 1], history[1]);
+        assert_eq!(vec![1, 2, 4, 8, 14, 1], history[6]);
+        assert_eq!(
+            cards.sum_of_winning_card_instances(0, 5) - 1,
+            history[6].iter().sum::<u32>()
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Model = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        Almanac::parse(input)
+    }
+
+    fn part1(almanac: &Almanac) -> Result<Answer, SolveError> {
+        Ok(almanac.lowest_location().into())
+    }
+
+    fn part2(almanac: &Almanac) -> Result<Answer, SolveError> {
+        Ok(almanac.lowest_location_for_seed_ranges().into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    seeds_ranges: Vec<Range<u64>>,
+    maps: Vec<AlmanacMap>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day5::DAY, input);
+        let blocks = parser.blocks();
+        let Some((seeds_line, map_blocks)) = blocks.split_first() else {
+            return Err(parser.error(input, "\"seeds:\""));
+        };
+
+        let seeds: Vec<u64> = parser.labelled_numbers(seeds_line, "seeds")?;
+        if !seeds.len().is_multiple_of(2) {
+            return Err(parser.error(
+                &seeds_line[seeds_line.len()..],
+                "a length for the last range of seeds",
+            ));
+        }
+        let seeds_ranges: Vec<_> = seeds
+            .chunks(2)
+            .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
+            .collect();
+
+        let maps = map_blocks
+            .iter()
+            .map(|block| parser.within(block, AlmanacMap::parse))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            seeds,
+            maps,
+            seeds_ranges,
+        })
+    }
+
+    pub fn lowest_location(&self) -> u64 {
+        self.seeds
+            .iter()
+            .map(|seed| self.seed_location(*seed))
+            .min()
+            .unwrap()
+    }
+
+    pub fn lowest_location_for_seed_ranges(&self) -> u64 {
+        self.seeds_ranges
+            .iter()
+            .map(|seeds_range| {
+                seeds_range
+                    .clone()
+                    .map(|seed| self.seed_location(seed))
+                    .min()
+                    .unwrap()
+            })
+            .min()
+            .unwrap()
+    }
+
+    pub fn seed_location(&self, seed: u64) -> u64 {
+        self.maps
+            .iter()
+            .fold(seed, |acc, almanac_map| almanac_map.map(acc))
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct AlmanacMap {
+    /// What is mapped to what, as in "seed-to-soil"
+    name: String,
+    ranges_sources_start: Vec<u64>,
+    ranges_dests_start: Vec<u64>,
+    ranges_length: Vec<u64>,
+}
+
+impl AlmanacMap {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day5::DAY, input);
+        let mut lines = input.lines();
+        let header = lines.next().unwrap_or_default();
+        let Some(name) = header.strip_suffix(" map:") else {
+            return Err(parser.error(header, "a map header like \"seed-to-soil map:\""));
+        };
+
+        let mut res = Self {
+            name: name.to_owned(),
+            ..Self::default()
+        };
+        for line in lines {
+            let nums: Vec<u64> = parser.numbers(line)?;
+            let &[dest_start, source_start, length] = nums.as_slice() else {
+                return Err(parser.error(line, "a destination start, source start and length"));
+            };
+
+            res.ranges_dests_start.push(dest_start);
+            res.ranges_sources_start.push(source_start);
+            res.ranges_length.push(length);
+        }
+
+        Ok(res)
+    }
+
+    pub fn map(&self, num: u64) -> u64 {
+        for i in 0..self.ranges_sources_start.len() {
+            let range_source_start = self.ranges_sources_start[i];
+            let range_dest_start = self.ranges_dests_start[i];
+            let range_length = self.ranges_length[i];
+
+            if (range_source_start..(range_source_start + range_length)).contains(&num) {
+                return range_dest_start + (num - range_source_start);
+            }
+        }
+        num
+    }
+}
+
+impl fmt::Display for Almanac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in &self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        writeln!(f)?;
+        for map in &self.maps {
+            write!(f, "\n{}", map)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AlmanacMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} map:", self.name)?;
+        for i in 0..self.ranges_sources_start.len() {
+            writeln!(
+                f,
+                "{} {} {}",
+                self.ranges_dests_start[i], self.ranges_sources_start[i], self.ranges_length[i]
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const NAMES: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    fn almanac_map(name: &'static str) -> impl Strategy<Value = AlmanacMap> {
+        prop::collection::vec((0..1_u64 << 32, 0..1_u64 << 32, 1..1_u64 << 16), 0..6).prop_map(
+            move |ranges| AlmanacMap {
+                name: name.to_owned(),
+                ranges_dests_start: ranges.iter().map(|range| range.0).collect(),
+                ranges_sources_start: ranges.iter().map(|range| range.1).collect(),
+                ranges_length: ranges.iter().map(|range| range.2).collect(),
+            },
+        )
+    }
+
+    fn almanac() -> impl Strategy<Value = Almanac> {
+        let seeds = prop::collection::vec((0..1_u64 << 32, 1..1_u64 << 16), 0..5);
+        let maps = (0..=NAMES.len()).prop_flat_map(|count| {
+            NAMES[..count]
+                .iter()
+                .map(|name| almanac_map(name))
+                .collect::<Vec<_>>()
+        });
+
+        (seeds, maps).prop_map(|(seed_ranges, maps)| Almanac {
+            seeds: seed_ranges
+                .iter()
+                .flat_map(|(start, length)| [*start, *length])
+                .collect(),
+            seeds_ranges: seed_ranges
+                .iter()
+                .map(|(start, length)| *start..start + length)
+                .collect(),
+            maps,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(almanac in almanac()) {
+            let printed = almanac.to_string();
+            prop_assert_eq!(Ok(almanac), Almanac::parse(&printed));
+        }
+    }
+
+    #[test]
+    fn test_almanac_map() {
+        let example_map_input = r#"seed-to-soil map:
+50 98 2
+52 50 48
+"#;
+
+        let almanac_map = AlmanacMap::parse(example_map_input).unwrap();
+
+        assert_eq!(53, almanac_map.map(51));
+        assert_eq!(100, almanac_map.map(100));
+    }
+
+    #[test]
+    fn test_parser() {
+        let example_input = include_str!("../../examples/day5/example1.txt");
+
+        assert!(!Almanac::parse(example_input)
+            .unwrap()
+            .maps
+            .first()
+            .unwrap()
+            .ranges_sources_start
+            .is_empty())
+    }
+}
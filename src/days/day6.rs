@@ -1,45 +1,49 @@
-use std::fs;
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
 
-fn part1(input: &str) -> u64 {
-    Games::parse(input).combinations()
-}
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Model = Games;
+
+    fn parse(input: &str) -> Result<Games, ParseError> {
+        Games::parse(input)
+    }
 
-fn part2(input: &str) -> u64 {
-    Games::parse(input).join_numbers().combinations()
+    fn part1(games: &Games) -> Result<Answer, SolveError> {
+        Ok(games.combinations().into())
+    }
+
+    fn part2(games: &Games) -> Result<Answer, SolveError> {
+        Ok(games.join_numbers().combinations().into())
+    }
 }
 
 #[derive(Debug)]
-struct Games {
+pub struct Games {
     times: Vec<u64>,
     distances: Vec<u64>,
 }
 
 impl Games {
-    fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day6::DAY, input);
         let mut lines = input.lines();
-        let times: Vec<u64> = lines
+        let times: Vec<u64> = parser.labelled_numbers(lines.next().unwrap_or_default(), "Time")?;
+        let distances_line = lines
             .next()
-            .unwrap()
-            .strip_prefix("Time:")
-            .unwrap()
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .map(|num| num.trim().parse().unwrap())
-            .collect();
-        let distances: Vec<u64> = lines
-            .next()
-            .unwrap()
-            .strip_prefix("Distance:")
-            .unwrap()
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .map(|num| num.trim().parse().unwrap())
-            .collect();
+            .ok_or_else(|| parser.error_at_end("\"Distance:\""))?;
+        let distances: Vec<u64> = parser.labelled_numbers(distances_line, "Distance")?;
+        if distances.len() != times.len() {
+            return Err(parser.error(distances_line, "as many distances as times"));
+        }
 
-        Games { times, distances }
+        Ok(Games { times, distances })
     }
 
-    fn join_numbers(&self) -> Self {
+    pub fn join_numbers(&self) -> Self {
         let join_numbers_f = |nums: &Vec<u64>| {
             vec![nums
                 .iter()
@@ -56,7 +60,7 @@ impl Games {
         }
     }
 
-    fn combinations(&self) -> u64 {
+    pub fn combinations(&self) -> u64 {
         self.times
             .iter()
             .zip(self.distances.iter())
@@ -67,7 +71,24 @@ impl Games {
             .unwrap()
     }
 
-    fn min_press_time(record: u64, race_duration: u64) -> u64 {
+    /// Duration and record distance of each race
+    pub fn races(&self) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.times
+            .iter()
+            .copied()
+            .zip(self.distances.iter().copied())
+    }
+
+    /// Slow reference for `min_press_time` and `max_press_time`, trying every
+    /// press time. None when the record cannot be beaten
+    pub fn press_times_brute_force(record: u64, race_duration: u64) -> Option<(u64, u64)> {
+        let winning = || {
+            (0..=race_duration).filter(move |press| Self::distance(*press, race_duration) > record)
+        };
+        Some((winning().next()?, winning().next_back()?))
+    }
+
+    pub fn min_press_time(record: u64, race_duration: u64) -> u64 {
         let mut from_time = 0;
         let mut to_time = race_duration;
 
@@ -96,7 +117,7 @@ impl Games {
         }
     }
 
-    fn max_press_time(record: u64, race_duration: u64) -> u64 {
+    pub fn max_press_time(record: u64, race_duration: u64) -> u64 {
         let mut from_time = 0;
         let mut to_time = race_duration;
 
@@ -125,18 +146,11 @@ impl Games {
         }
     }
 
-    fn distance(press_time: u64, race_duration: u64) -> u64 {
+    pub fn distance(press_time: u64, race_duration: u64) -> u64 {
         press_time * (race_duration - press_time)
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("src/bin/input6.txt").unwrap();
-
-    println!("Answer to day6 part 1: {}", part1(&input));
-    println!("Answer to day6 part 2: {}", part2(&input));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,6 +165,12 @@ mod tests {
         assert_eq!(5, Games::max_press_time(9, 7));
     }
 
+    #[test]
+    fn test_press_times_brute_force() {
+        assert_eq!(Some((2, 5)), Games::press_times_brute_force(9, 7));
+        assert_eq!(None, Games::press_times_brute_force(12, 7));
+    }
+
     #[test]
     fn test_simple() {
         assert_eq!(
@@ -162,20 +182,4 @@ mod tests {
             .combinations()
         )
     }
-
-    #[test]
-    fn test_part_1() {
-        let example_input = r#"Time:      7  15   30
-Distance:  9  40  200
-"#;
-
-        assert_eq!(288, part1(example_input));
-    }
-
-    // #[test]
-    // fn test_part_2() {
-    //     let example_input = r#""#;
-
-    //     assert_eq!(281, part2(example_input));
-    // }
 }
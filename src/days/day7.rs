@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Model = CamelCardsGame;
+
+    fn parse(input: &str) -> Result<CamelCardsGame, ParseError> {
+        CamelCardsGame::parse(input)
+    }
+
+    fn part1(game: &CamelCardsGame) -> Result<Answer, SolveError> {
+        Ok(game.total_winnings().into())
+    }
+
+    fn part2(game: &CamelCardsGame) -> Result<Answer, SolveError> {
+        Ok(game.total_winnings().into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CamelCardsGame {
+    hands: Vec<Hand>,
+    bets: Vec<u64>,
+}
+
+impl CamelCardsGame {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day7::DAY, input);
+        let mut hands = Vec::new();
+        let mut bets = Vec::new();
+        for line in input.lines() {
+            let (hand, bet) = parser.split_once(line, ' ')?;
+
+            hands.push(Hand::parse(hand).map_err(|error| error.within(input, hand))?);
+            bets.push(parser.number(bet)?);
+        }
+
+        Ok(Self { hands, bets })
+    }
+
+    pub fn total_winnings(&self) -> u64 {
+        let mut hands_with_bets: Vec<(&Hand, &u64)> =
+            self.hands.iter().zip(self.bets.iter()).collect();
+        hands_with_bets.sort_by_key(|(hand, _)| *hand);
+
+        hands_with_bets
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (_, bet))| (rank as u64 + 1) * bet)
+            .sum()
+    }
+}
+
+impl fmt::Display for CamelCardsGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (hand, bet) in self.hands.iter().zip(&self.bets) {
+            writeln!(f, "{} {}", hand, bet)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Hand {
+    cards: Vec<Card>,
+    cards_count: HashMap<Card, u64>,
+}
+
+impl Hand {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day7::DAY, input);
+        let cards: Vec<Card> = input
+            .char_indices()
+            .map(|(i, c)| Card::parse(c).ok_or_else(|| parser.error(&input[i..], "a card")))
+            .collect::<Result<_, _>>()?;
+        if cards.len() != 5 {
+            return Err(parser.error(input, "a hand of 5 cards"));
+        }
+        let cards_count = Self::cards_count(&cards);
+
+        Ok(Self { cards, cards_count })
+    }
+
+    fn cards_count(cards: &[Card]) -> HashMap<Card, u64> {
+        let mut res = HashMap::new();
+        let mut jokers_count = 0;
+        for card in cards {
+            if *card == Card::joker() {
+                jokers_count += 1;
+            } else {
+                *res.entry(*card).or_default() += 1
+            }
+        }
+        let joker = Card::joker();
+        // ties broken by strength so that parsing the same hand always gives
+        // the jokers to the same card
+        let most_common_card = res
+            .iter()
+            .max_by_key(|(k, v)| (**v, k.strength()))
+            .unwrap_or((&joker, &5))
+            .0;
+        *res.entry(*most_common_card).or_default() += jokers_count;
+        res
+    }
+
+    fn type_strength(&self) -> u64 {
+        let mut cards_counts: Vec<u64> = self.cards_count.values().copied().collect();
+        cards_counts.sort();
+
+        match *cards_counts.as_slice() {
+            [5] => 6,          // five of a kind
+            [1, 4] => 5,       // four of a kind
+            [2, 3] => 4,       // full house
+            [1, 1, 3] => 3,    // three of a kind
+            [1, 2, 2] => 2,    // two pair
+            [1, 1, 1, 2] => 1, // one pair
+            _ => 0,            // high card
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cards
+            .iter()
+            .try_for_each(|card| write!(f, "{}", card.card))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.type_strength() != other.type_strength() {
+            return self.type_strength().cmp(&other.type_strength());
+        }
+
+        for (card, other_card) in self.cards.iter().zip(other.cards.iter()) {
+            if card.strength() != other_card.strength() {
+                return card.strength().cmp(&other_card.strength());
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+struct Card {
+    card: char,
+}
+
+impl Card {
+    fn parse(card: char) -> Option<Self> {
+        "AKQJT98765432".contains(card).then_some(Card { card })
+    }
+
+    fn strength(&self) -> u64 {
+        match self.card {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            // 'J' => 10,
+            'T' => 10,
+            'J' => 0,
+            c => c.to_digit(10).unwrap() as u64,
+        }
+    }
+
+    fn joker() -> Self {
+        Card { card: 'J' }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn hand() -> impl Strategy<Value = Hand> {
+        prop::collection::vec(
+            prop::sample::select("AKQJT98765432".chars().collect::<Vec<_>>()),
+            5,
+        )
+        .prop_map(|cards| {
+            let cards: Vec<Card> = cards.into_iter().map(|card| Card { card }).collect();
+            let cards_count = Hand::cards_count(&cards);
+            Hand { cards, cards_count }
+        })
+    }
+
+    fn game() -> impl Strategy<Value = CamelCardsGame> {
+        prop::collection::vec((hand(), 1..1000_u64), 0..20).prop_map(|hands_with_bets| {
+            let (hands, bets) = hands_with_bets.into_iter().unzip();
+            CamelCardsGame { hands, bets }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(game in game()) {
+            let printed = game.to_string();
+            prop_assert_eq!(Ok(game), CamelCardsGame::parse(&printed));
+        }
+    }
+
+    #[test]
+    fn test_parse_hand_from_string() {
+        assert_eq!(
+            Hand {
+                cards: vec![
+                    Card { card: '3' },
+                    Card { card: '2' },
+                    Card { card: 'T' },
+                    Card { card: '3' },
+                    Card { card: 'K' },
+                ],
+                cards_count: HashMap::from([
+                    (Card { card: '3' }, 2),
+                    (Card { card: '2' }, 1),
+                    (Card { card: 'T' }, 1),
+                    (Card { card: 'K' }, 1)
+                ])
+            },
+            Hand::parse("32T3K").unwrap()
+        )
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::iter;
+
+use crate::math::{self, MathError};
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Model = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map.required_steps("AAA", "ZZZ").into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer, SolveError> {
+        Ok(map.required_steps_for_ghosts()?.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self::Left => 'L',
+            Self::Right => 'R',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    instructions: Vec<Direction>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Map {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day8::DAY, input);
+        let mut lines = input.lines();
+
+        let instructions_line = lines.next().unwrap_or_default();
+        let instructions: Vec<Direction> = instructions_line
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::from_char(c)
+                    .ok_or_else(|| parser.error(&instructions_line[i..], "'L' or 'R'"))
+            })
+            .collect::<Result<_, _>>()?;
+        if instructions.is_empty() {
+            return Err(parser.error(instructions_line, "a list of instructions"));
+        }
+
+        match lines.next() {
+            Some("") => {}
+            Some(line) => return Err(parser.error(line, "an empty line")),
+            None => return Err(parser.error_at_end("an empty line")),
+        }
+        let nodes = lines
+            .map(|line| {
+                let (id, (left, right)) = parser.pair_record(line)?;
+                Ok((id.to_owned(), (left.to_owned(), right.to_owned())))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            instructions,
+            nodes,
+        })
+    }
+
+    fn next_location(&self, current_location: &str, instruction: &Direction) -> &str {
+        let (left, right) = self.nodes.get(current_location).unwrap();
+        match instruction {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    pub fn required_steps(&self, from: &str, destination: &str) -> u64 {
+        self.walk(from)
+            .position(|(location, _)| location == destination)
+            .expect("the walk reaches the destination") as u64
+    }
+
+    /// Locations walked through from `from` by following the instructions over
+    /// and over, each with the index of the instruction followed to leave it.
+    /// Ends at a location that is not a node.
+    pub fn walk<'a>(&'a self, from: &'a str) -> impl Iterator<Item = (&'a str, usize)> + 'a {
+        iter::successors(Some((from, 0)), move |(location, index)| {
+            self.nodes.contains_key(*location).then(|| {
+                let next = self.next_location(location, &self.instructions[*index]);
+                (next, (index + 1) % self.instructions.len())
+            })
+        })
+    }
+
+    /// The left and right of the node `id`
+    pub fn node(&self, id: &str) -> Option<(&str, &str)> {
+        let (left, right) = self.nodes.get(id)?;
+        Some((left, right))
+    }
+
+    /// The instructions as 'L' and 'R'
+    pub fn instructions(&self) -> String {
+        self.instructions.iter().map(|d| d.to_char()).collect()
+    }
+
+    /// Steps until all ghosts stand on a node ending with Z at once, which
+    /// happens after a multiple of the steps each ghost takes to get there
+    pub fn required_steps_for_ghosts(&self) -> Result<u64, MathError> {
+        let start_locations: Vec<&str> = self
+            .nodes
+            .keys()
+            .map(|id| id.as_str())
+            .filter(|id| id.ends_with("A"))
+            .collect();
+        let distances_to_finish: Vec<u64> = start_locations
+            .iter()
+            .map(|loc| self.first_finishing_distance_from(loc))
+            .collect();
+
+        math::lcm_of(&distances_to_finish)
+    }
+
+    /// Slow reference for `required_steps_for_ghosts`, walking all ghosts at
+    /// once. None when they are not all on a node ending with Z within `limit`
+    /// steps
+    pub fn required_steps_for_ghosts_brute_force(&self, limit: u64) -> Option<u64> {
+        // walked by index, as millions of steps may be needed
+        let ids: Vec<&str> = self.nodes.keys().map(|id| id.as_str()).collect();
+        let index: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let next: Vec<(usize, usize)> = ids
+            .iter()
+            .map(|id| {
+                let (left, right) = &self.nodes[*id];
+                (index[left.as_str()], index[right.as_str()])
+            })
+            .collect();
+        let finishes: Vec<bool> = ids.iter().map(|id| id.ends_with("Z")).collect();
+
+        let mut locations: Vec<usize> = (0..ids.len()).filter(|i| ids[*i].ends_with("A")).collect();
+        let mut instructions = self.instructions.iter().cycle();
+        for steps in 0..=limit {
+            if locations.iter().all(|location| finishes[*location]) {
+                return Some(steps);
+            }
+            let instruction = instructions.next().unwrap();
+            for location in &mut locations {
+                *location = match instruction {
+                    Direction::Left => next[*location].0,
+                    Direction::Right => next[*location].1,
+                };
+            }
+        }
+        None
+    }
+
+    pub fn first_finishing_distance_from(&self, starting_location: &str) -> u64 {
+        let mut current_location = starting_location;
+        let mut instructions = self.instructions.iter().cycle().enumerate();
+
+        loop {
+            let (idx, instruction) = instructions.next().unwrap();
+            current_location = self.next_location(current_location, instruction);
+
+            if current_location.ends_with("Z") {
+                return idx as u64 + 1;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.instructions())?;
+        writeln!(f)?;
+
+        let mut nodes: Vec<_> = self.nodes.iter().collect();
+        nodes.sort();
+        for (id, (left, right)) in nodes {
+            writeln!(f, "{} = ({}, {})", id, left, right)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn map() -> impl Strategy<Value = Map> {
+        let direction = prop_oneof![Just(Direction::Left), Just(Direction::Right)];
+        let id = || "[A-Z0-9]{3}";
+        let nodes = prop::collection::hash_map(id(), (id(), id()), 0..20);
+
+        (prop::collection::vec(direction, 1..20), nodes).prop_map(|(instructions, nodes)| Map {
+            instructions,
+            nodes,
+        })
+    }
+
+    #[test]
+    fn test_walk() {
+        let map = Map::parse(include_str!("../../examples/day8/example2.txt")).unwrap();
+
+        let walked: Vec<(&str, usize)> = map.walk("AAA").take(7).collect();
+
+        assert_eq!(
+            vec![
+                ("AAA", 0),
+                ("BBB", 1),
+                ("AAA", 2),
+                ("BBB", 0),
+                ("AAA", 1),
+                ("BBB", 2),
+                ("ZZZ", 0)
+            ],
+            walked
+        );
+        assert_eq!(6, map.required_steps("AAA", "ZZZ"));
+    }
+
+    #[test]
+    fn test_ghosts_overflowing() {
+        // ghosts walking loops of distinct primes, whose product exceeds u64
+        let mut input = "L\n\n".to_owned();
+        for (ghost, length) in [10007, 10009, 10037, 10039, 10061].iter().enumerate() {
+            let id = |step: u32| match step {
+                0 => format!("{}A", ghost),
+                step if step == *length => format!("{}Z", ghost),
+                step => format!("{}N{}", ghost, step),
+            };
+            for step in 0..=*length {
+                let next = id(step % length + 1);
+                input.push_str(&format!("{} = ({}, {})\n", id(step), next, next));
+            }
+        }
+        let map = Map::parse(&input).unwrap();
+
+        assert_eq!(
+            Err(SolveError::Math(MathError::Overflow)),
+            Day8::part2(&map)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(map in map()) {
+            let printed = map.to_string();
+            prop_assert_eq!(Ok(map), Map::parse(&printed));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use crate::parse::{ParseError, Parser};
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Model = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Vec<Sequence>, ParseError> {
+        Parser::new(Self::DAY, input).lines(Sequence::parse)
+    }
+
+    fn part1(sequences: &Vec<Sequence>) -> Result<Answer, SolveError> {
+        Ok(sequences
+            .iter()
+            .map(Sequence::predict_next)
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(sequences: &Vec<Sequence>) -> Result<Answer, SolveError> {
+        Ok(sequences
+            .iter()
+            .map(Sequence::predict_previous)
+            .sum::<i64>()
+            .into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sequence(Vec<i64>);
+
+impl Sequence {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(Day9::DAY, line);
+        let nums: Vec<i64> = line
+            .split(' ')
+            .map(|num| parser.number(num))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Sequence(nums))
+    }
+
+    pub fn predict_next(&self) -> i64 {
+        self.differences()
+            .iter()
+            .rfold(0, |last_prediction, diffs_sequence| {
+                last_prediction + diffs_sequence.last().unwrap()
+            })
+    }
+
+    pub fn predict_previous(&self) -> i64 {
+        self.differences()
+            .iter()
+            .rfold(0, |last_prediction, diffs_sequence| {
+                diffs_sequence.first().unwrap() - last_prediction
+            })
+    }
+
+    fn differences(&self) -> Vec<Vec<i64>> {
+        let mut res = vec![self.0.clone()];
+
+        loop {
+            let last_seq = res.last().unwrap();
+            if Self::all_zeroes(last_seq) {
+                return res;
+            }
+            res.push(
+                last_seq
+                    .windows(2)
+                    .map(|window| window[1] - window[0])
+                    .collect(),
+            );
+        }
+    }
+
+    fn all_zeroes(seq: &[i64]) -> bool {
+        seq.iter().all(|n| *n == 0)
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers: Vec<String> = self.0.iter().map(i64::to_string).collect();
+        write!(f, "{}", numbers.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_print_parse_round_trip(numbers in prop::collection::vec(any::<i64>(), 1..25)) {
+            let sequence = Sequence(numbers);
+            let printed = sequence.to_string();
+            prop_assert_eq!(Ok(sequence), Sequence::parse(&printed));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::parse::ParseError;
+use crate::solution::{self, Parsed, Part, Solution};
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+    /// Parts known to give a wrong answer, flagged in the output
+    pub broken_parts: &'static [Part],
+}
+
+impl Day {
+    const fn of<S: Solution + 'static>() -> Self {
+        Day {
+            number: S::DAY,
+            parse: solution::parse::<S>,
+            broken_parts: &[],
+        }
+    }
+
+    const fn with_broken_parts(self, broken_parts: &'static [Part]) -> Self {
+        Day {
+            broken_parts,
+            ..self
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    // Currently part1 is broken
+    Day::of::<day7::Day7>().with_broken_parts(&[Part::One]),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
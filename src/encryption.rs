@@ -0,0 +1,235 @@
+//! Inputs kept encrypted at rest, as `inputN.txt.enc` next to where the
+//! plaintext `inputN.txt` would be, so that they can be committed without
+//! publishing them. The key is given in hexadecimal in [`KEY_VAR`].
+//!
+//! An encrypted file is a magic header, a random nonce, then the input
+//! encrypted and authenticated with ChaCha20-Poly1305.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+pub use crate::input_files::{encrypted_path, is_encrypted_path, EXTENSION};
+
+/// Environment variable holding the key, 64 hexadecimal digits
+pub const KEY_VAR: &str = "AOC_INPUTS_KEY";
+
+const MAGIC: &[u8] = b"aoc2023 encrypted input v1\n";
+
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    /// Suggests a new key when displayed, only made up then
+    MissingKey,
+    InvalidKey,
+    NotEncrypted,
+    /// Either the key is not the one the input was encrypted with, or the
+    /// file was altered
+    WrongKey,
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionError::MissingKey => write!(
+                f,
+                "${} is not set, set it to the key the inputs were encrypted with, \
+                 or to encrypt them for the first time to a new key like {}",
+                KEY_VAR,
+                Key::generate().to_hex()
+            ),
+            EncryptionError::InvalidKey => {
+                write!(f, "${} must be 64 hexadecimal digits", KEY_VAR)
+            }
+            EncryptionError::NotEncrypted => write!(f, "not an encrypted input"),
+            EncryptionError::WrongKey => write!(
+                f,
+                "cannot decrypt with the key in ${}, or the file is corrupted",
+                KEY_VAR
+            ),
+            EncryptionError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for EncryptionError {}
+
+#[derive(Clone)]
+pub struct Key(chacha20poly1305::Key);
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    pub fn from_env() -> Result<Self, EncryptionError> {
+        match env::var(KEY_VAR) {
+            Ok(hex) => Self::parse(&hex),
+            Err(_) => Err(EncryptionError::MissingKey),
+        }
+    }
+
+    pub fn parse(hex: &str) -> Result<Self, EncryptionError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(EncryptionError::InvalidKey);
+        }
+        let mut key = chacha20poly1305::Key::default();
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| EncryptionError::InvalidKey)?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| EncryptionError::InvalidKey)?;
+        }
+        Ok(Self(key))
+    }
+
+    pub fn generate() -> Self {
+        Self(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &str) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(&key.0)
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("inputs are far smaller than what can be encrypted at once");
+
+    let mut encrypted = MAGIC.to_vec();
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend(ciphertext);
+    encrypted
+}
+
+pub fn decrypt(key: &Key, encrypted: &[u8]) -> Result<String, EncryptionError> {
+    let rest = encrypted
+        .strip_prefix(MAGIC)
+        .ok_or(EncryptionError::NotEncrypted)?;
+    if rest.len() < NONCE_LEN {
+        return Err(EncryptionError::NotEncrypted);
+    }
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let plaintext = ChaCha20Poly1305::new(&key.0)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| EncryptionError::WrongKey)?;
+    // only valid UTF-8 is ever encrypted
+    String::from_utf8(plaintext).map_err(|_| EncryptionError::WrongKey)
+}
+
+/// Reads and decrypts the encrypted input at `path`
+pub fn read(key: &Key, path: &Path) -> Result<String, EncryptionError> {
+    let encrypted = fs::read(path).map_err(|error| EncryptionError::Io(path.to_owned(), error))?;
+    decrypt(key, &encrypted)
+}
+
+/// Replaces the plaintext input at `path` by its encrypted version, giving
+/// the path of the latter
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<PathBuf, EncryptionError> {
+    let io_error = |error| EncryptionError::Io(path.to_owned(), error);
+    let plaintext = fs::read_to_string(path).map_err(io_error)?;
+    let encrypted = encrypted_path(path);
+    fs::write(&encrypted, encrypt(key, &plaintext))
+        .map_err(|error| EncryptionError::Io(encrypted.clone(), error))?;
+    fs::remove_file(path).map_err(io_error)?;
+    Ok(encrypted)
+}
+
+/// Replaces the encrypted input at `path` by its plaintext, giving the path of
+/// the latter
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<PathBuf, EncryptionError> {
+    let io_error = |error| EncryptionError::Io(path.to_owned(), error);
+    let plaintext = decrypt(key, &fs::read(path).map_err(io_error)?)?;
+    let decrypted = path.with_extension("");
+    fs::write(&decrypted, plaintext)
+        .map_err(|error| EncryptionError::Io(decrypted.clone(), error))?;
+    fs::remove_file(path).map_err(io_error)?;
+    Ok(decrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::parse(KEY).unwrap();
+
+        let encrypted = encrypt(&key, "Time:      7  15   30\n");
+
+        assert!(!encrypted.windows(4).any(|window| window == b"Time"));
+        assert_eq!(
+            "Time:      7  15   30\n",
+            decrypt(&key, &encrypted).unwrap()
+        );
+        // a new nonce every time
+        assert_ne!(encrypted, encrypt(&key, "Time:      7  15   30\n"));
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let key = Key::parse(KEY).unwrap();
+        let mut encrypted = encrypt(&key, "1abc2");
+
+        assert!(matches!(
+            decrypt(&Key::generate(), &encrypted),
+            Err(EncryptionError::WrongKey)
+        ));
+        *encrypted.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            decrypt(&key, &encrypted),
+            Err(EncryptionError::WrongKey)
+        ));
+        assert!(matches!(
+            decrypt(&key, b"1abc2"),
+            Err(EncryptionError::NotEncrypted)
+        ));
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(KEY, Key::parse(KEY).unwrap().to_hex());
+        assert_eq!(64, Key::generate().to_hex().len());
+        assert!(Key::parse("0011").is_err());
+        assert!(Key::parse(&KEY.replace('a', "g")).is_err());
+        assert!(Key::parse(&"é".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_missing_key_suggests_a_new_one() {
+        let message = EncryptionError::MissingKey.to_string();
+
+        let suggested = message.rsplit(' ').next().unwrap();
+        assert!(Key::parse(suggested).is_ok(), "{}", message);
+        assert_ne!(message, EncryptionError::MissingKey.to_string());
+    }
+
+    #[test]
+    fn test_migrate_files() {
+        let dir = env::temp_dir().join("aoc2023-test-migrate-files");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input1.txt");
+        fs::write(&path, "1abc2\n").unwrap();
+        let key = Key::parse(KEY).unwrap();
+
+        let encrypted = encrypt_file(&key, &path).unwrap();
+        assert_eq!(dir.join("input1.txt.enc"), encrypted);
+        assert!(!path.exists());
+
+        assert_eq!(path, decrypt_file(&key, &encrypted).unwrap());
+        assert_eq!("1abc2\n", fs::read_to_string(&path).unwrap());
+        assert!(!encrypted.exists());
+    }
+}
@@ -0,0 +1,391 @@
+//! Downloads the inputs of the days from the puzzle website, authenticated by
+//! the session cookie of a logged in user.
+//!
+//! Inputs are saved where the runner reads them from first, encrypted when a
+//! key is set, and never downloaded again once there. Requests are spaced by
+//! a minimum interval, remembered between runs, so as not to load the website.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use toml::{Table, Value};
+
+use crate::encryption::{self, EncryptionError, Key};
+use crate::input;
+
+/// Optional settings of the downloads, as in
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// min_interval_secs = 5
+/// ```
+pub const CONFIG_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/aoc.toml");
+
+/// Environment variable holding the session cookie, over the config file's
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable holding the base URL, over the config file's
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// File holding the time of the last request
+const LAST_REQUEST_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/last-fetch");
+
+const YEAR: u16 = 2023;
+
+const USER_AGENT: &str = concat!(
+    "aoc2023/",
+    env!("CARGO_PKG_VERSION"),
+    " (input downloader of a solutions repository)"
+);
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    Config(String),
+    Http(ureq::Error),
+    /// The website answered with another status than 200 OK
+    Status(u16, String),
+    /// The key to save the inputs encrypted with is invalid
+    Encryption(EncryptionError),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(
+                f,
+                "no session cookie, set ${} or `session` in {}",
+                SESSION_VAR, CONFIG_FILE
+            ),
+            FetchError::Config(error) => write!(f, "invalid {}: {}", CONFIG_FILE, error),
+            FetchError::Http(error) => write!(f, "request failed: {}", error),
+            FetchError::Status(status, body) => {
+                write!(f, "the website answered {}: {}", status, body.trim())
+            }
+            FetchError::Encryption(error) => write!(f, "{}", error),
+            FetchError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// How an input was obtained
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// Already on disk, at this path
+    Cached(PathBuf),
+    /// Downloaded and saved to this path
+    Downloaded(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub base_url: String,
+    /// Only needed to download inputs that are not cached yet
+    session: Option<String>,
+    /// Directories the inputs are looked for in, new ones being saved to the
+    /// first
+    pub cache_dirs: Vec<PathBuf>,
+    /// Saves the inputs encrypted with it when set
+    pub key: Option<Key>,
+    pub min_interval: Duration,
+    pub last_request_file: PathBuf,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<&str>, cache_dirs: Vec<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.map(|session| session.trim().to_owned()),
+            cache_dirs,
+            key: None,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request_file: PathBuf::from(LAST_REQUEST_FILE),
+        }
+    }
+
+    /// Settings from the environment, then the config file, caching the inputs
+    /// where the runner reads them, encrypted with the key in
+    /// [`encryption::KEY_VAR`] if set
+    pub fn from_config() -> Result<Self, FetchError> {
+        let config: Table = match fs::read_to_string(CONFIG_FILE) {
+            Ok(content) => content
+                .parse()
+                .map_err(|error: toml::de::Error| FetchError::Config(error.to_string()))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Table::new(),
+            Err(error) => return Err(FetchError::Io(PathBuf::from(CONFIG_FILE), error)),
+        };
+        let setting = |var: &str, key: &str| -> Result<Option<String>, FetchError> {
+            if let Ok(value) = env::var(var) {
+                return Ok(Some(value));
+            }
+            match config.get(key) {
+                None => Ok(None),
+                Some(Value::String(value)) => Ok(Some(value.clone())),
+                Some(_) => Err(FetchError::Config(format!("{} is not a string", key))),
+            }
+        };
+
+        let session = setting(SESSION_VAR, "session")?;
+        let base_url = setting(BASE_URL_VAR, "base_url")?;
+        let mut fetcher = Self::new(
+            base_url.as_deref().unwrap_or(DEFAULT_BASE_URL),
+            session.as_deref(),
+            input::default_dirs(),
+        );
+        match config.get("min_interval_secs") {
+            None => {}
+            Some(Value::Integer(secs)) if *secs >= 0 => {
+                fetcher.min_interval = Duration::from_secs(*secs as u64)
+            }
+            Some(_) => {
+                return Err(FetchError::Config(
+                    "min_interval_secs is not a number of seconds".to_owned(),
+                ))
+            }
+        }
+        fetcher.key = match Key::from_env() {
+            Ok(key) => Some(key),
+            Err(EncryptionError::MissingKey) => None,
+            Err(error) => return Err(FetchError::Encryption(error)),
+        };
+        Ok(fetcher)
+    }
+
+    /// The input of `day`, downloaded unless already cached
+    pub fn fetch(&self, day: u8) -> Result<Fetched, FetchError> {
+        let paths: Vec<PathBuf> = self
+            .cache_dirs
+            .iter()
+            .map(|dir| dir.join(input::file_name(day)))
+            .collect();
+        // in the order the runner reads them, new days being scaffolded with
+        // an empty input
+        if let Some(cached) = paths
+            .iter()
+            .flat_map(|path| [encryption::encrypted_path(path), path.clone()])
+            .find(|path| fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0))
+        {
+            return Ok(Fetched::Cached(cached));
+        }
+        let path = paths
+            .first()
+            .ok_or_else(|| FetchError::Config("no directory to save the inputs to".to_owned()))?;
+
+        let input = self.download(day)?;
+        let (path, content) = match &self.key {
+            Some(key) => (
+                encryption::encrypted_path(path),
+                encryption::encrypt(key, &input),
+            ),
+            None => (path.clone(), input.into_bytes()),
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| FetchError::Io(dir.to_path_buf(), error))?;
+        }
+        fs::write(&path, content).map_err(|error| FetchError::Io(path.clone(), error))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn download(&self, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::NoSession)?;
+        self.wait_for_rate_limit()?;
+
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let mut response = agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", session))
+            .call()
+            .map_err(FetchError::Http)?;
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(FetchError::Http)?;
+
+        match response.status().as_u16() {
+            200 => Ok(body),
+            status => Err(FetchError::Status(status, body)),
+        }
+    }
+
+    /// Sleeps until the minimum interval has passed since the last request,
+    /// then records this one
+    fn wait_for_rate_limit(&self) -> Result<(), FetchError> {
+        let path = &self.last_request_file;
+        let io_error = |error| FetchError::Io(path.clone(), error);
+
+        let last_request = fs::read_to_string(path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request {
+            let elapsed = SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or_default();
+            if let Some(wait) = self.min_interval.checked_sub(elapsed) {
+                thread::sleep(wait);
+            }
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        fs::write(path, now.to_string()).map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    /// Serves every request with `status` and `body` on a local port, giving
+    /// its base URL and the request lines and cookies received
+    fn stub_server(status: &'static str, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut head = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    head.push(line);
+                }
+                let cookie = head
+                    .iter()
+                    .find_map(|line| {
+                        line.strip_prefix("cookie: ")
+                            .or(line.strip_prefix("Cookie: "))
+                    })
+                    .unwrap_or_default()
+                    .to_owned();
+                received
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", head[0], cookie));
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base_url, requests)
+    }
+
+    fn fetcher(base_url: &str, name: &str) -> Fetcher {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        let mut fetcher = Fetcher::new(base_url, Some("abc123"), vec![dir.join("inputs")]);
+        fetcher.last_request_file = dir.join("last-fetch");
+        fetcher.min_interval = Duration::ZERO;
+        fetcher
+    }
+
+    #[test]
+    fn test_downloads_then_uses_the_cache() {
+        let (base_url, requests) = stub_server("200 OK", "1abc2\n");
+        let fetcher = fetcher(&base_url, "aoc2023-test-fetch-cache");
+        let path = fetcher.cache_dirs[0].join("input1.txt");
+
+        assert_eq!(Fetched::Downloaded(path.clone()), fetcher.fetch(1).unwrap());
+        assert_eq!(Fetched::Cached(path.clone()), fetcher.fetch(1).unwrap());
+
+        assert_eq!("1abc2\n", fs::read_to_string(path).unwrap());
+        assert_eq!(
+            vec!["GET /2023/day/1/input HTTP/1.1 session=abc123"],
+            *requests.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_saves_encrypted_with_a_key() {
+        let (base_url, requests) = stub_server("200 OK", "1abc2\n");
+        let mut fetcher = fetcher(&base_url, "aoc2023-test-fetch-encrypted");
+        let key = Key::generate();
+        fetcher.key = Some(key.clone());
+        let path = fetcher.cache_dirs[0].join("input1.txt.enc");
+
+        assert_eq!(Fetched::Downloaded(path.clone()), fetcher.fetch(1).unwrap());
+        assert_eq!(Fetched::Cached(path.clone()), fetcher.fetch(1).unwrap());
+
+        assert!(!fetcher.cache_dirs[0].join("input1.txt").exists());
+        assert_eq!("1abc2\n", encryption::read(&key, &path).unwrap());
+        assert_eq!(1, requests.lock().unwrap().len());
+    }
+
+    #[test]
+    fn test_reports_error_statuses_without_caching() {
+        let (base_url, _) = stub_server("400 Bad Request", "Please log in.");
+        let fetcher = fetcher(&base_url, "aoc2023-test-fetch-error");
+
+        let error = fetcher.fetch(2).unwrap_err();
+
+        assert_eq!(
+            "the website answered 400: Please log in.",
+            error.to_string()
+        );
+        assert!(!fetcher.cache_dirs[0].join("input2.txt").exists());
+    }
+
+    #[test]
+    fn test_needs_a_session_to_download_only() {
+        let mut fetcher = fetcher("http://127.0.0.1:1", "aoc2023-test-fetch-session");
+        fetcher.session = None;
+        let path = fetcher.cache_dirs[0].join("input3.txt");
+
+        assert!(matches!(fetcher.fetch(3), Err(FetchError::NoSession)));
+        fs::create_dir_all(&fetcher.cache_dirs[0]).unwrap();
+        fs::write(&path, "467..114..\n").unwrap();
+        assert_eq!(Fetched::Cached(path), fetcher.fetch(3).unwrap());
+    }
+
+    #[test]
+    fn test_spaces_requests() {
+        let (base_url, requests) = stub_server("200 OK", "input");
+        let mut fetcher = fetcher(&base_url, "aoc2023-test-fetch-rate-limit");
+        fetcher.min_interval = Duration::from_millis(300);
+
+        let start = Instant::now();
+        fetcher.fetch(1).unwrap();
+        fetcher.fetch(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(2, requests.lock().unwrap().len());
+    }
+}
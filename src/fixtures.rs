@@ -0,0 +1,151 @@
+//! Checks every puzzle example in `examples/`. Each `examples/dayN/<name>.txt`
+//! comes with a `<name>.toml` sidecar holding the expected answers, as in
+//!
+//! ```toml
+//! part1 = 35
+//! part2 = 46
+//! ```
+//!
+//! where a part without an expected answer is not checked. Besides `cargo
+//! test`, the examples of a single day can be checked from the command line.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use crate::days;
+use crate::solution::Part;
+
+pub const EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+#[derive(Debug)]
+pub struct Example {
+    pub day: u8,
+    pub input: PathBuf,
+    pub expected: Vec<(Part, String)>,
+}
+
+pub fn load_examples(dir: &Path) -> Result<Vec<Example>, String> {
+    let mut examples = Vec::new();
+
+    for day_dir in read_dir_sorted(dir)? {
+        let day = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+            .ok_or_else(|| format!("{} is not named like dayN", day_dir.display()))?;
+
+        for input in read_dir_sorted(&day_dir)? {
+            if input
+                .extension()
+                .is_some_and(|extension| extension == "txt")
+            {
+                let expected = load_expected(&input.with_extension("toml"))?;
+                examples.push(Example {
+                    day,
+                    input,
+                    expected,
+                });
+            }
+        }
+    }
+
+    Ok(examples)
+}
+
+pub(crate) fn load_expected(sidecar: &Path) -> Result<Vec<(Part, String)>, String> {
+    let table: Table = fs::read_to_string(sidecar)
+        .map_err(|error| format!("{}: {}", sidecar.display(), error))?
+        .parse()
+        .map_err(|error| format!("{}: {}", sidecar.display(), error))?;
+
+    let mut expected = Vec::new();
+    for (key, value) in table {
+        let part = match key.as_str() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            _ => return Err(format!("{}: unknown key {:?}", sidecar.display(), key)),
+        };
+        let answer = match value {
+            Value::Integer(answer) => answer.to_string(),
+            Value::String(answer) => answer,
+            _ => return Err(format!("{}: {} is not an answer", sidecar.display(), key)),
+        };
+        expected.push((part, answer));
+    }
+    Ok(expected)
+}
+
+fn read_dir_sorted(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths = fs::read_dir(dir)
+        .and_then(|entries| entries.map(|entry| Ok(entry?.path())).collect())
+        .map_err(|error| format!("{}: {}", dir.display(), error))
+        .map(|paths: Vec<PathBuf>| paths)?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Checks every example of `day`, returning how many there are and a
+/// description of every mismatch
+pub fn check_day(day: u8) -> Result<(usize, Vec<String>), String> {
+    let examples: Vec<Example> = load_examples(Path::new(EXAMPLES_DIR))?
+        .into_iter()
+        .filter(|example| example.day == day)
+        .collect();
+    Ok((examples.len(), examples.iter().flat_map(check).collect()))
+}
+
+/// Checks the example, returning a description of every mismatch
+pub fn check(example: &Example) -> Vec<String> {
+    let name = example.input.display();
+    let Some(day) = days::find(example.day) else {
+        return vec![format!("{}: no solution for day{}", name, example.day)];
+    };
+    let input = match fs::read_to_string(&example.input) {
+        Ok(input) => input,
+        Err(error) => return vec![format!("{}: {}", name, error)],
+    };
+    let parsed = match (day.parse)(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => return vec![format!("{}: {}", name, error)],
+    };
+
+    example
+        .expected
+        .iter()
+        .filter(|(part, _)| !day.broken_parts.contains(part))
+        .filter_map(|(part, expected)| {
+            let answer = match parsed.solve(*part) {
+                Ok(answer) => answer.to_string(),
+                Err(error) => format!("error: {}", error),
+            };
+            (answer != *expected).then(|| {
+                format!(
+                    "{} part {}: expected {}, got {}",
+                    name, part, expected, answer
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let examples = load_examples(Path::new(EXAMPLES_DIR)).unwrap();
+        assert!(!examples.is_empty());
+
+        let failures: Vec<String> = examples.iter().flat_map(check).collect();
+
+        assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_check_day() {
+        assert_eq!((3, vec![]), check_day(8).unwrap());
+        assert_eq!((0, vec![]), check_day(25).unwrap());
+    }
+}
@@ -0,0 +1,578 @@
+//! Synthetic puzzle inputs, so that the solutions can be run and benchmarked
+//! without sharing the real inputs. Every generator is deterministic: the same
+//! seed and size always give the same input.
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::point::{Direction, Point};
+
+/// Writes the input of a day, scaled by a size whose meaning depends on the day
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+const GENERATORS: &[(u8, Generator)] = &[
+    (1, day1),
+    (2, day2),
+    (3, day3),
+    (4, day4),
+    (5, day5),
+    (6, day6),
+    (7, day7),
+    (8, day8),
+    (9, day9),
+    (10, day10),
+];
+
+pub fn generator(day: u8) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, generator)| *generator)
+}
+
+/// Generates the input of `day` from `seed`, if the day has a generator
+pub fn input(day: u8, seed: u64, size: usize) -> Option<String> {
+    generator(day).map(|generator| generator(&mut Rng::new(seed), size))
+}
+
+/// Small seeded pseudorandom generator (SplitMix64), good enough for inputs
+/// and stable across platforms and releases, unlike the standard hasher
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, which must not be empty
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "cannot pick below 0");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        low + self.below(high - low + 1)
+    }
+
+    pub fn signed_range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        low + self.below(high.abs_diff(low) + 1) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True once every `times` on average
+    pub fn one_in(&mut self, times: u64) -> bool {
+        self.below(times) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+const DIGIT_NAMES: &[&str] = &[
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters with digits and spelled out digits, each having at
+/// least one real digit for part 1
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.range(1..=6) {
+            match rng.below(3) {
+                0 => line.push(char::from(b'1' + rng.below(9) as u8)),
+                1 => line.push_str(DIGIT_NAMES[rng.index(DIGIT_NAMES.len())]),
+                _ => line.push(char::from(b'a' + rng.below(26) as u8)),
+            }
+        }
+        if !line.chars().any(|c| c.is_ascii_digit()) {
+            let at = rng.index(line.len() + 1);
+            line.insert(at, char::from(b'1' + rng.below(9) as u8));
+        }
+        input.push_str(&line);
+        input.push('\n');
+    }
+    input
+}
+
+/// `size` games of up to 6 reveals
+fn day2(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for id in 1..=size {
+        let reveals: Vec<String> = (0..rng.range(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let shown = rng.range(1..=3) as usize;
+                colors[..shown]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.range(1..=20), color))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, reveals.join("; ")));
+    }
+    input
+}
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+
+/// A schematic of `size` rows and columns, with numbers of up to 3 digits and
+/// symbols scattered between them
+fn day3(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let digits = rng.range(1..=3) as usize;
+            if rng.one_in(4) && row.len() + digits < size {
+                let number =
+                    rng.range(10_u64.pow(digits as u32 - 1)..=10_u64.pow(digits as u32) - 1);
+                row.push_str(&number.to_string());
+                // numbers next to each other on a row would read as one
+                row.push('.');
+            } else if rng.one_in(8) {
+                row.push(*rng.pick(SYMBOLS));
+            } else {
+                row.push('.');
+            }
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+    input
+}
+
+/// `size` cards never winning copies past the last card, and winning few enough
+/// for the total of instances to stay below a million
+fn day4(rng: &mut Rng, size: usize) -> String {
+    const MAX_INSTANCES: u64 = 1_000_000;
+    let mut copies = vec![1_u64; size];
+    let mut instances = size as u64;
+    let mut input = String::new();
+
+    for id in 0..size {
+        let mut numbers: Vec<u64> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, rest) = numbers.split_at(5);
+
+        let mut matches = (rng.below(4) as usize).min(size - id - 1);
+        if instances + copies[id] * matches as u64 > MAX_INSTANCES {
+            matches = 0;
+        }
+        let card_copies = copies[id];
+        for won in &mut copies[id + 1..=id + matches] {
+            *won += card_copies;
+        }
+        instances += card_copies * matches as u64;
+
+        let mut card_numbers: Vec<u64> = winning[..matches].to_vec();
+        card_numbers.extend_from_slice(&rest[..8 - matches]);
+        rng.shuffle(&mut card_numbers);
+
+        let joined = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            id + 1,
+            joined(winning),
+            joined(&card_numbers)
+        ));
+    }
+    input
+}
+
+const CATEGORIES: &[&str] = &[
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac of `size` seed ranges and maps of `size` ranges each, sending
+/// disjoint ranges of sources to disjoint ranges of destinations. The seed
+/// ranges are short to keep part 2 brute forceable.
+fn day5(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let span = 1000 * size as u64;
+    let seeds: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.below(span), rng.range(1..=1000)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for categories in CATEGORIES.windows(2) {
+        input.push_str(&format!("\n{}-to-{} map:\n", categories[0], categories[1]));
+
+        let lengths: Vec<u64> = (0..size).map(|_| rng.range(1..=1000)).collect();
+        let mut destinations: Vec<usize> = (0..size).collect();
+        rng.shuffle(&mut destinations);
+
+        let mut source_starts = Vec::with_capacity(size);
+        let mut source = rng.below(1000);
+        for length in &lengths {
+            source_starts.push(source);
+            source += length + rng.below(100);
+        }
+        let mut dest_starts = vec![0; size];
+        let mut dest = rng.below(1000);
+        for &range in &destinations {
+            dest_starts[range] = dest;
+            dest += lengths[range] + rng.below(100);
+        }
+
+        let mut ranges: Vec<usize> = (0..size).collect();
+        rng.shuffle(&mut ranges);
+        for range in ranges {
+            input.push_str(&format!(
+                "{} {} {}\n",
+                dest_starts[range], source_starts[range], lengths[range]
+            ));
+        }
+    }
+    input
+}
+
+/// `size` races, up to 4 for part 2 to fit in u64, of two digit times each
+/// with a record that can be beaten, which joined still leave a record that
+/// can be beaten
+fn day6(rng: &mut Rng, size: usize) -> String {
+    let races = size.clamp(1, 4);
+    let times: Vec<u64> = (0..races).map(|_| rng.range(10..=99)).collect();
+    // the best distance is reached holding the button half the race
+    let distances: Vec<u64> = times
+        .iter()
+        .map(|time| rng.range(1..=time * time / 4 - 1))
+        .collect();
+
+    let row = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .map(|n| format!("{:>4}", n))
+            .collect::<Vec<String>>()
+            .join("   ")
+    };
+    format!("Time:    {}\nDistance:{}\n", row(&times), row(&distances))
+}
+
+const CARDS: &[char] = &[
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// `size` distinct hands, so that their ranks do not depend on how ties are sorted
+fn day7(rng: &mut Rng, size: usize) -> String {
+    let mut hands = HashSet::new();
+    let mut input = String::new();
+    while hands.len() < size {
+        let hand: String = (0..5).map(|_| *rng.pick(CARDS)).collect();
+        if hands.insert(hand.clone()) {
+            input.push_str(&format!("{} {}\n", hand, rng.range(1..=1000)));
+        }
+    }
+    input
+}
+
+const CYCLE_PRIMES: &[u64] = &[3, 5, 7, 11, 13, 17, 19, 23];
+const NAME_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A network for `size` instructions, up to 500, where each ghost walks a loop
+/// from its `..A` node to its `..Z` node and back to the node after its start,
+/// its loop being the length of the instructions times a prime distinct for
+/// every ghost. The ghosts then all first meet on `..Z` nodes after the
+/// instructions times the product of their primes. The first ghost starts at
+/// `AAA` and ends at `ZZZ`.
+fn day8(rng: &mut Rng, size: usize) -> String {
+    let instructions: Vec<char> = (0..size.clamp(1, 500))
+        .map(|_| *rng.pick(&['L', 'R']))
+        .collect();
+    let mut primes = CYCLE_PRIMES.to_vec();
+    rng.shuffle(&mut primes);
+    let ghosts = rng.range(2..=4) as usize;
+
+    // none ends with A or Z, to only be start or end nodes of the ghosts
+    let mut names: Vec<String> = NAME_CHARS
+        .iter()
+        .flat_map(|&a| NAME_CHARS.iter().map(move |&b| [a, b]))
+        .flat_map(|[a, b]| {
+            NAME_CHARS
+                .iter()
+                .filter(|&&c| c != b'A' && c != b'Z')
+                .map(move |&c| String::from_utf8(vec![a, b, c]).unwrap())
+        })
+        .collect();
+    rng.shuffle(&mut names);
+    let mut names = names.into_iter();
+
+    let mut nodes: Vec<String> = Vec::new();
+    let mut node_names: Vec<String> = Vec::new();
+    let mut edges: Vec<(String, String, char)> = Vec::new();
+    for (ghost, prime) in primes.iter().take(ghosts).enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_owned(), "ZZZ".to_owned()),
+            _ => {
+                let prefix = format!(
+                    "{}{}",
+                    NAME_CHARS[ghost / NAME_CHARS.len()] as char,
+                    NAME_CHARS[ghost % NAME_CHARS.len()] as char
+                );
+                (format!("{}A", prefix), format!("{}Z", prefix))
+            }
+        };
+
+        let length = instructions.len() * *prime as usize;
+        let mut path = vec![start];
+        path.extend(names.by_ref().take(length - 1));
+        path.push(end);
+        // the instruction taken on each node is always the same, as the loop is
+        // a multiple of the instructions
+        for (step, from) in path.iter().enumerate() {
+            let to = if step + 1 < path.len() {
+                &path[step + 1]
+            } else {
+                &path[1]
+            };
+            edges.push((
+                from.clone(),
+                to.clone(),
+                instructions[step % instructions.len()],
+            ));
+        }
+        node_names.extend(path);
+    }
+
+    for (from, to, instruction) in edges {
+        let other = rng.pick(&node_names);
+        let (left, right) = match instruction {
+            'L' => (&to, other),
+            _ => (other, &to),
+        };
+        nodes.push(format!("{} = ({}, {})\n", from, left, right));
+    }
+    rng.shuffle(&mut nodes);
+
+    let instructions: String = instructions.into_iter().collect();
+    format!("{}\n\n{}", instructions, nodes.concat())
+}
+
+/// `size` sequences of 21 values of polynomials of degree up to 5
+fn day9(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..=rng.below(6))
+            .map(|_| rng.signed_range(-5..=5))
+            .collect();
+        let values: Vec<String> = (0..21_i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+    input
+}
+
+const PIPES: &[char] = &['|', '-', 'L', 'J', '7', 'F', '.', '.', '.'];
+
+/// A grid of `size` rows and columns, at least 5, with a single loop and junk
+/// pipes around and within it. The loop runs along a ragged top edge in the
+/// upper half of the grid and a ragged bottom edge in the lower half, so that
+/// it never touches itself. It starts on its top left corner, from which it
+/// goes east and south.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) as i64;
+    let middle = size / 2;
+    // a margin of junk is kept around the loop
+    let columns = (size - 2) as usize;
+    let mut tops: Vec<i64> = (0..columns)
+        .map(|_| rng.signed_range(1..=middle - 1))
+        .collect();
+    let mut bottoms: Vec<i64> = (0..columns)
+        .map(|_| rng.signed_range(middle + 1..=size - 2))
+        .collect();
+    // the first and last columns are a single vertical run
+    tops[columns - 1] = tops[columns - 2];
+    bottoms[0] = bottoms[1];
+
+    let mut path = vec![Point::new(1, tops[0])];
+    let walk = |path: &mut Vec<Point>, direction: Direction, y: i64| {
+        let mut current = *path.last().unwrap();
+        while current.y != y {
+            current = current.step(direction);
+            path.push(current);
+        }
+    };
+    for &top in &tops[1..] {
+        let current = path.last().unwrap().step(Direction::East);
+        path.push(current);
+        let direction = if top < current.y {
+            Direction::North
+        } else {
+            Direction::South
+        };
+        walk(&mut path, direction, top);
+    }
+    walk(&mut path, Direction::South, bottoms[columns - 1]);
+    for &bottom in bottoms[..columns - 1].iter().rev() {
+        let current = path.last().unwrap().step(Direction::West);
+        path.push(current);
+        let direction = if bottom < current.y {
+            Direction::North
+        } else {
+            Direction::South
+        };
+        walk(&mut path, direction, bottom);
+    }
+    walk(&mut path, Direction::North, tops[0] + 1);
+
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(PIPES)).collect())
+        .collect();
+    let start = path[0];
+    // only the two loop pipes may connect to the start
+    for direction in Direction::ALL {
+        let neighbour = start.step(direction);
+        rows[neighbour.y as usize][neighbour.x as usize] = '.';
+    }
+    for (i, point) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+        let mut ends = [direction_to(*point, previous), direction_to(*point, next)];
+        ends.sort_by_key(|direction| Direction::CARDINAL.iter().position(|d| d == direction));
+        rows[point.y as usize][point.x as usize] = match ends {
+            [Direction::North, Direction::South] => '|',
+            [Direction::East, Direction::West] => '-',
+            [Direction::North, Direction::East] => 'L',
+            [Direction::North, Direction::West] => 'J',
+            [Direction::South, Direction::West] => '7',
+            [Direction::East, Direction::South] => 'F',
+            _ => unreachable!("the loop never turns back"),
+        };
+    }
+    rows[start.y as usize][start.x as usize] = 'S';
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn direction_to(from: Point, to: Point) -> Direction {
+    Direction::CARDINAL
+        .into_iter()
+        .find(|direction| from.step(*direction) == to)
+        .expect("the points of the loop are next to each other")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, day10::PipesGraph, day8::Map};
+    use crate::Part;
+
+    #[test]
+    fn test_rng_stays_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert!((-2..=2).contains(&rng.signed_range(-2..=2)));
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_every_day_solves_generated_inputs() {
+        for day in days::DAYS {
+            for seed in 0..5 {
+                let input = input(day.number, seed, 12).expect("every day has a generator");
+                let parsed = (day.parse)(&input).unwrap_or_else(|error| {
+                    panic!("seed {}: {}\n{}", seed, error, input);
+                });
+                for part in Part::ALL {
+                    if let Err(error) = parsed.solve(part) {
+                        panic!("seed {} part {}: {}\n{}", seed, part, error, input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in days::DAYS {
+            assert_eq!(input(day.number, 42, 20), input(day.number, 42, 20));
+            assert_ne!(input(day.number, 42, 20), input(day.number, 43, 20));
+        }
+    }
+
+    #[test]
+    fn test_ghosts_meet_after_the_product_of_their_cycles() {
+        for seed in 0..5 {
+            let map = Map::parse(&input(8, seed, 10).unwrap()).unwrap();
+            let steps = map.required_steps_for_ghosts().unwrap();
+
+            // the instructions times the product of at least two distinct primes
+            assert!(steps.is_multiple_of(10));
+            let primes: Vec<u64> = CYCLE_PRIMES
+                .iter()
+                .copied()
+                .filter(|prime| (steps / 10).is_multiple_of(*prime))
+                .collect();
+            assert!(primes.len() >= 2);
+            assert_eq!(steps, 10 * primes.iter().product::<u64>());
+            assert_eq!(
+                map.first_finishing_distance_from("AAA"),
+                map.required_steps("AAA", "ZZZ")
+            );
+        }
+    }
+
+    #[test]
+    fn test_pipes_have_a_single_loop() {
+        for seed in 0..20 {
+            let input = input(10, seed, 9).unwrap();
+            let graph = PipesGraph::parse(&input).unwrap();
+
+            // the loop goes around at least the middle row of the grid
+            let loop_points = graph.find_loop();
+            assert!(loop_points.len() >= 18, "seed {}:\n{}", seed, input);
+            assert_eq!(loop_points.len() as u64 / 2, graph.max_distance_in_loop());
+        }
+    }
+}
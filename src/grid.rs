@@ -0,0 +1,277 @@
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Parser};
+use crate::point::{Direction, Point};
+
+/// Position of a cell in a [`Grid`], as `(x, y)` with `(0, 0)` the top left
+/// corner, `x` growing to the right and `y` growing downwards
+pub type Position = (usize, usize);
+
+/// Rectangular grid of cells stored densely, row after row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells, all set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid with a cell per character and a row per line of `input`,
+    /// every row having the same number of cells. Blank lines at the end are
+    /// ignored. `parse_cell` gives `None` for characters that are not
+    /// `expected` in a cell
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let parser = Parser::new(day, input);
+        let mut lines = input.trim_end_matches(['\n', '\r']).lines().peekable();
+        let width = match lines.peek() {
+            Some(line) if !line.is_empty() => line.chars().count(),
+            _ => return Err(parser.error(input, "a grid")),
+        };
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if x == width {
+                    return Err(parser.error(&line[i..], "end of line"));
+                }
+                cells.push(parse_cell(c).ok_or_else(|| parser.error(&line[i..], expected))?);
+            }
+            if cells.len() < (height + 1) * width {
+                return Err(parser.error(&line[line.len()..], format!("a row of {} cells", width)));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Every position of the grid, row after row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell of the grid with its position, row after row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Positions within the grid sharing an edge with `position`, clockwise
+    /// from the north
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::CARDINAL)
+    }
+
+    /// Positions within the grid touching `position`, diagonals included,
+    /// clockwise from the north
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ALL)
+    }
+
+    /// Point one step away from `point` in `direction`, if that is still
+    /// within the grid
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        let next = point.step(direction);
+        self.contains(next.position()?).then_some(next)
+    }
+
+    /// Grid of the same size with `f` applied to every cell
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .iter()
+            .filter_map(move |direction| self.step(position.into(), *direction)?.position())
+    }
+
+    fn index_of(&self, (x, y): Position) -> Option<usize> {
+        self.contains((x, y)).then(|| y * self.width + x)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", position))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        point
+            .position()
+            .and_then(|position| self.get(position))
+            .unwrap_or_else(|| panic!("{:?} out of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} out of the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(0, input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n\n").unwrap();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 1)));
+        assert_eq!(4, grid[(0, 1)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!(
+            (2, 2, "a digit"),
+            (error.line, error.column, error.expected.as_str())
+        );
+
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!((2, 3), (error.line, error.column));
+
+        let error = digits("123\n4567").unwrap_err();
+        assert_eq!((2, 4), (error.line, error.column));
+
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456").unwrap();
+
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![2, 5], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]],
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, '.');
+
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)],
+            grid.neighbours8((1, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = Grid::new(3, 2, '.');
+
+        assert_eq!(
+            Some(Point::new(2, 1)),
+            grid.step(Point::new(1, 0), Direction::SouthEast)
+        );
+        assert_eq!(None, grid.step(Point::new(2, 1), Direction::East));
+        assert_eq!(None, grid.step(Point::new(0, 1), Direction::West));
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(1, 0)] = 7;
+
+        assert_eq!(
+            vec![0, 7, 0, 0],
+            grid.iter().map(|(_, cell)| *cell).collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.get_mut((0, 2)));
+    }
+}
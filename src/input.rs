@@ -0,0 +1,376 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::encryption::{self, EncryptionError, Key};
+use crate::input_files;
+
+pub use crate::input_files::file_name;
+
+/// Environment variable pointing to a directory of `inputN.txt` files
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+const DEFAULT_INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin");
+
+/// An input included in the binary by the `embedded-inputs` feature, which
+/// variants are used depends on the inputs in `src/bin`
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+enum Embedded {
+    Plain(&'static str),
+    Encrypted(&'static [u8]),
+}
+
+/// The inputs in `src/bin` when built, listed by `build.rs`
+#[cfg(feature = "embedded-inputs")]
+const EMBEDDED: &[(u8, Embedded)] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+#[cfg(not(feature = "embedded-inputs"))]
+const EMBEDDED: &[(u8, Embedded)] = &[];
+
+/// An input along with where it was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub text: String,
+    pub source: Source,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    /// Included in the binary when built
+    Embedded,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => {
+                let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+                write!(f, "{}", path.strip_prefix(root).unwrap_or(path).display())
+            }
+            Source::Embedded => write!(f, "embedded in the binary"),
+        }
+    }
+}
+
+/// Where the default input of a day is looked for
+#[derive(Debug, Clone, PartialEq)]
+enum Location {
+    /// A directory, with a description of where it comes from
+    Dir(String, PathBuf),
+    Embedded,
+}
+
+/// Where the input was explicitly asked to be read from on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputArg {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputArg {
+    pub fn parse(arg: &str) -> Self {
+        match arg {
+            "-" => InputArg::Stdin,
+            path => InputArg::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    day: u8,
+    tried: Vec<(String, io::Error)>,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read the input for day{}, tried:", self.day)?;
+        for (location, error) in &self.tried {
+            write!(f, "\n  {}: {}", location, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the input of a day from the command line argument if one was given,
+/// otherwise from the directory in [`INPUTS_DIR_VAR`], then from the inputs
+/// embedded in the binary with the `embedded-inputs` feature, falling back to
+/// `src/bin`. Encrypted inputs are decrypted with the key in
+/// [`encryption::KEY_VAR`].
+pub fn load(day: u8, arg: Option<&InputArg>) -> Result<Input, InputError> {
+    let key = Key::from_env();
+    match arg {
+        Some(InputArg::Path(path)) => match read(path, key.as_ref()) {
+            Ok(text) => Ok(Input {
+                text,
+                source: Source::File(path.clone()),
+            }),
+            Err(error) => Err(InputError {
+                day,
+                tried: vec![(path.display().to_string(), error)],
+            }),
+        },
+        Some(InputArg::Stdin) => {
+            let mut text = String::new();
+            match io::stdin().read_to_string(&mut text) {
+                Ok(_) => Ok(Input {
+                    text,
+                    source: Source::Stdin,
+                }),
+                Err(error) => Err(InputError {
+                    day,
+                    tried: vec![("stdin".to_owned(), error)],
+                }),
+            }
+        }
+        None => read_first(day, &locations(), key.as_ref()),
+    }
+}
+
+/// Files the default input of a day is read from, plaintext or encrypted, the
+/// first existing one being used
+pub fn default_paths(day: u8) -> Vec<PathBuf> {
+    default_dirs()
+        .into_iter()
+        .flat_map(|dir| {
+            let path = dir.join(file_name(day));
+            [encryption::encrypted_path(&path), path]
+        })
+        .collect()
+}
+
+/// Directories the default inputs are read from, in order
+pub fn default_dirs() -> Vec<PathBuf> {
+    input_dirs().into_iter().map(|(_, dir)| dir).collect()
+}
+
+fn input_dirs() -> Vec<(String, PathBuf)> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os(INPUTS_DIR_VAR) {
+        dirs.push((format!("${}", INPUTS_DIR_VAR), PathBuf::from(dir)));
+    }
+    dirs.push(("default".to_owned(), PathBuf::from(DEFAULT_INPUTS_DIR)));
+    dirs
+}
+
+/// The directories of [`input_dirs`], the embedded inputs coming before the
+/// default directory when there are some
+fn locations() -> Vec<Location> {
+    let mut locations: Vec<Location> = input_dirs()
+        .into_iter()
+        .map(|(origin, dir)| Location::Dir(origin, dir))
+        .collect();
+    if cfg!(feature = "embedded-inputs") {
+        locations.insert(locations.len() - 1, Location::Embedded);
+    }
+    locations
+}
+
+/// Reads the first input found in `locations`, decrypting it with `key` if
+/// encrypted, or failing with why there is no key
+fn read_first(
+    day: u8,
+    locations: &[Location],
+    key: Result<&Key, &EncryptionError>,
+) -> Result<Input, InputError> {
+    let mut tried = Vec::new();
+
+    for location in locations {
+        let (origin, dir) = match location {
+            Location::Dir(origin, dir) => (origin, dir),
+            Location::Embedded => {
+                match read_embedded(day, key) {
+                    Ok(text) => {
+                        return Ok(Input {
+                            text,
+                            source: Source::Embedded,
+                        })
+                    }
+                    Err(error) => tried.push(("embedded".to_owned(), error)),
+                }
+                continue;
+            }
+        };
+        // the plaintext is only missing once encrypted, so its error is not
+        // worth reporting then
+        let path = input_files::input_path(dir, day);
+        match read(&path, key) {
+            Ok(text) => {
+                return Ok(Input {
+                    text,
+                    source: Source::File(path),
+                })
+            }
+            Err(error) => tried.push((format!("{} ({})", path.display(), origin), error)),
+        }
+    }
+
+    Err(InputError { day, tried })
+}
+
+fn read_embedded(day: u8, key: Result<&Key, &EncryptionError>) -> io::Result<String> {
+    let embedded = EMBEDDED
+        .iter()
+        .find(|(embedded_day, _)| *embedded_day == day)
+        .map(|(_, embedded)| *embedded)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "not in src/bin when the binary was built",
+            )
+        })?;
+    match embedded {
+        Embedded::Plain(text) => Ok(text.to_owned()),
+        Embedded::Encrypted(bytes) => {
+            encryption::decrypt(usable(key)?, bytes).map_err(io::Error::other)
+        }
+    }
+}
+
+/// Reads the input at `path`, decrypting it with `key` if it is encrypted
+fn read(path: &Path, key: Result<&Key, &EncryptionError>) -> io::Result<String> {
+    if !encryption::is_encrypted_path(path) {
+        return fs::read_to_string(path);
+    }
+    encryption::read(usable(key)?, path).map_err(|error| match error {
+        EncryptionError::Io(_, error) => error,
+        error => io::Error::other(error),
+    })
+}
+
+/// The key, or why there is none as the error of reading an encrypted input
+fn usable<'a>(key: Result<&'a Key, &EncryptionError>) -> io::Result<&'a Key> {
+    key.map_err(|error| io::Error::other(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_falls_back_to_next_dir() {
+        let dir = env::temp_dir().join("aoc2023-test-falls-back-to-next-dir");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input1.txt"), "1abc2").unwrap();
+        let path = dir.join("input1.txt");
+        let dirs = vec![
+            Location::Dir("missing".to_owned(), PathBuf::from("does/not/exist")),
+            Location::Dir("existing".to_owned(), dir),
+        ];
+
+        let input = read_first(1, &dirs, Err(&EncryptionError::MissingKey)).unwrap();
+
+        assert_eq!("1abc2", input.text);
+        assert_eq!(Source::File(path), input.source);
+    }
+
+    #[test]
+    fn test_error_names_every_location_tried() {
+        let dirs = vec![
+            Location::Dir("first".to_owned(), PathBuf::from("does/not/exist")),
+            Location::Dir("second".to_owned(), PathBuf::from("nor/does/this")),
+        ];
+
+        let message = read_first(1, &dirs, Err(&EncryptionError::MissingKey))
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("does/not/exist/input1.txt (first)"));
+        assert!(message.contains("nor/does/this/input1.txt (second)"));
+    }
+
+    #[test]
+    fn test_reads_encrypted_inputs() {
+        let dir = env::temp_dir().join("aoc2023-test-reads-encrypted-inputs");
+        fs::create_dir_all(&dir).unwrap();
+        // left over from before encrypting, as the embedded inputs ignore it
+        fs::write(dir.join("input1.txt"), "stale").unwrap();
+        let key = Key::generate();
+        fs::write(
+            dir.join("input1.txt.enc"),
+            encryption::encrypt(&key, "1abc2"),
+        )
+        .unwrap();
+        let dirs = vec![Location::Dir("encrypted".to_owned(), dir.clone())];
+
+        let input = read_first(1, &dirs, Ok(&key)).unwrap();
+        assert_eq!("1abc2", input.text);
+        assert_eq!(Source::File(dir.join("input1.txt.enc")), input.source);
+
+        let missing = EncryptionError::MissingKey;
+        let message = read_first(1, &dirs, Err(&missing)).unwrap_err().to_string();
+        assert!(message.contains("input1.txt.enc (encrypted): $AOC_INPUTS_KEY is not set"));
+        let message = read_first(1, &dirs, Ok(&Key::generate()))
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("cannot decrypt with the key in $AOC_INPUTS_KEY"));
+    }
+
+    #[test]
+    fn test_embedded_inputs_come_before_the_default_dir() {
+        let locations = locations();
+
+        assert_eq!(
+            Some(&Location::Dir(
+                "default".to_owned(),
+                PathBuf::from(DEFAULT_INPUTS_DIR)
+            )),
+            locations.last()
+        );
+        assert_eq!(
+            cfg!(feature = "embedded-inputs"),
+            locations.contains(&Location::Embedded)
+        );
+        let embedded = read_first(1, &[Location::Embedded], Err(&EncryptionError::MissingKey));
+        if cfg!(feature = "embedded-inputs") {
+            // unless only an encrypted input was embedded
+            match embedded {
+                Ok(input) => assert_eq!(Source::Embedded, input.source),
+                Err(error) => assert!(error.to_string().contains("is not set"), "{}", error),
+            }
+        } else {
+            assert!(embedded
+                .unwrap_err()
+                .to_string()
+                .contains("embedded: not in src/bin when the binary was built"));
+        }
+    }
+
+    #[test]
+    fn test_embedded_inputs_are_those_read_from_src_bin() {
+        for (day, embedded) in EMBEDDED {
+            let path = input_files::input_path(Path::new(DEFAULT_INPUTS_DIR), *day);
+            assert_eq!(
+                input_files::is_encrypted_path(&path),
+                matches!(embedded, Embedded::Encrypted(_)),
+                "day{}",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn test_source_is_shown_from_the_root() {
+        let path = Path::new(DEFAULT_INPUTS_DIR).join("input3.txt");
+
+        assert_eq!("src/bin/input3.txt", Source::File(path).to_string());
+        assert_eq!("stdin", Source::Stdin.to_string());
+    }
+
+    #[test]
+    fn test_parse_input_arg() {
+        assert_eq!(InputArg::Stdin, InputArg::parse("-"));
+        assert_eq!(
+            InputArg::Path(PathBuf::from("input.txt")),
+            InputArg::parse("input.txt")
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Names of the input files of the days, shared with `build.rs` so that the
+//! inputs embedded in the binary are the ones read from disk at runtime.
+
+use std::path::{Path, PathBuf};
+
+/// Extension added to the name of an encrypted input
+pub const EXTENSION: &str = "enc";
+
+pub fn file_name(day: u8) -> String {
+    format!("input{}.txt", day)
+}
+
+/// Where the encrypted version of the input at `path` is kept
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(EXTENSION);
+    PathBuf::from(name)
+}
+
+pub fn is_encrypted_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == EXTENSION)
+}
+
+/// The file of `dir` the input of `day` is read from: its encrypted version
+/// when there is one, since the plaintext is only left there until removed
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    let path = dir.join(file_name(day));
+    let encrypted = encrypted_path(&path);
+    if encrypted.exists() {
+        encrypted
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_input_path_prefers_the_encrypted_input() {
+        let dir = env::temp_dir().join("aoc2023-test-input-path");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        assert_eq!(dir.join("input1.txt"), input_path(&dir, 1));
+        fs::write(dir.join("input1.txt"), "1abc2").unwrap();
+        fs::write(dir.join("input1.txt.enc"), "").unwrap();
+        assert_eq!(dir.join("input1.txt.enc"), input_path(&dir, 1));
+        assert!(is_encrypted_path(&input_path(&dir, 1)));
+    }
+}
@@ -0,0 +1,39 @@
+//! Solutions to Advent of Code 2023, one module per day under [`days`].
+//!
+//! Every day implements [`Solution`]: its input is parsed once into a typed
+//! model, from which both parts are answered. The models and their domain
+//! methods are public so that other tools can reuse them, e.g.
+//!
+//! ```
+//! use aoc2023::days::day6::Day6;
+//! use aoc2023::{Answer, Solution};
+//!
+//! let races = Day6::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+//! assert_eq!(Ok(Answer::Unsigned(288)), Day6::part1(&races));
+//! ```
+
+pub mod answers;
+pub mod batch;
+pub mod days;
+pub mod encryption;
+pub mod fetch;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod input;
+mod input_files;
+pub mod math;
+pub mod oracle;
+pub mod parse;
+pub mod point;
+pub mod pool;
+pub mod render;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+pub mod stepper;
+pub mod timing;
+pub mod watch;
+
+pub use parse::ParseError;
+pub use solution::{Answer, Part, Solution, SolveError};
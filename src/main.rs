@@ -1,3 +1,602 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+use aoc2023::answers::{AcceptedAnswers, ANSWERS_FILE};
+use aoc2023::days::{self, Day, DAYS};
+use aoc2023::encryption::{self, Key};
+use aoc2023::fetch::{Fetched, Fetcher};
+use aoc2023::input::{self, InputArg};
+use aoc2023::report::{self, Format, PartReport, Status};
+use aoc2023::solution::Parsed;
+use aoc2023::timing::{self, DayTimings};
+use aoc2023::{batch, fixtures, generate, oracle, render, scaffold, stepper, watch, Part};
+
+const USAGE: &str =
+    "usage: aoc2023 <day> [part] [--input <path>|-] [--format text|json] [--trace <path>|-]
+       aoc2023 <day> [part] --inputs <dir> [--trace <path>|-]
+       aoc2023 all [--jobs <threads>] [--format text|json] [--trace <path>|-]
+       aoc2023 accept <day> [part] [--input <path>|-]
+       aoc2023 verify
+       aoc2023 examples <day>
+       aoc2023 watch <day> [--input <path>]
+       aoc2023 step 4|8|10 [--input <path>]
+       aoc2023 time <day>|all [--repeat <runs>] [--input <path>|-]
+       aoc2023 new <day>
+       aoc2023 fetch <day>
+       aoc2023 encrypt|decrypt
+       aoc2023 generate <day> [--seed <n>] [--size <n>]
+       aoc2023 differential <day> [--seeds <n>] [--size <n>]
+
+accept saves the answers of a day to answers.toml, verify reruns every day
+and fails if any answer differs from the accepted one or any part fails,
+skipping the parts not implemented yet. time reports how long
+parsing and each part take, with the min and median over repeated runs.
+examples checks the answers of a day to its examples in examples/dayN/.
+watch reruns the examples and the input of a day whenever its source, input
+or examples change, showing how the answers changed since the previous run.
+step goes through the simulation of day 4 (card copies), 8 (the walk from AAA
+to ZZZ) or 10 (the search around the loop) forward and back in the terminal.
+new creates the module of a day from a template, with an empty input file.
+fetch downloads the input of a day unless already there, with the session
+cookie in $AOC_SESSION or aoc.toml, waiting between requests. It is saved
+encrypted when $AOC_INPUTS_KEY is set.
+encrypt replaces every inputN.txt by inputN.txt.enc, encrypted with the key in
+$AOC_INPUTS_KEY (64 hexadecimal digits), and decrypt does the reverse.
+generate prints a synthetic input for a day, the same for the same seed (0 by
+default) and size (100 by default), whose meaning depends on the day.
+differential checks the optimized computations of days 6, 8 and 10 against a
+brute force on generated inputs, for --seeds seeds (100 by default) of every
+size up to --size (20 by default), printing the smallest input they disagree on.
+
+--inputs solves a day for every .txt (or .txt.enc) file in a directory, with a
+row per input giving its answers, timings and errors, checked against the
+expected answers of a name.toml sidecar when there is one, as for examples.
+
+all solves the days on as many threads as --jobs, one by default, still
+printing them in order.
+--format json prints a JSON object per line for each day and part, with its
+status, answer, type, timings, error and input source.
+--trace writes the pictures the days draw while solving to a file, or to stderr
+for -, coloured when it is a terminal.
+
+Without --input, inputs are read from $AOC_INPUTS_DIR/inputN.txt if set,
+then from the binary when built with the embedded-inputs feature, then from
+src/bin/inputN.txt, decrypting inputN.txt.enc instead if there";
+
 fn main() {
-    println!("Hello, world!");
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let input = take_option(&mut args, "--input").map(|arg| InputArg::parse(&arg));
+    let inputs = take_option(&mut args, "--inputs");
+    let repeat = take_option(&mut args, "--repeat").map(|arg| parse_count("--repeat", &arg));
+    let format = take_option(&mut args, "--format").map(|arg| parse_format(&arg));
+    let trace = take_option(&mut args, "--trace");
+    let jobs = take_option(&mut args, "--jobs").map(|arg| parse_count("--jobs", &arg));
+    let seed = take_option(&mut args, "--seed").map(|arg| parse_seed(&arg));
+    let seeds = take_option(&mut args, "--seeds").map(|arg| parse_count("--seeds", &arg));
+    let size = take_option(&mut args, "--size").map(|arg| parse_count("--size", &arg));
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if repeat.is_some() && args.first() != Some(&"time") {
+        exit_with_error("--repeat only applies to time");
+    }
+    let runs = repeat.unwrap_or(1);
+    if jobs.is_some() && args != ["all"] {
+        exit_with_error("--jobs only applies to all");
+    }
+    if seed.is_some() && args.first() != Some(&"generate") {
+        exit_with_error("--seed only applies to generate");
+    }
+    if seeds.is_some() && args.first() != Some(&"differential") {
+        exit_with_error("--seeds only applies to differential");
+    }
+    if size.is_some() && !matches!(args.first(), Some(&("generate" | "differential"))) {
+        exit_with_error("--size only applies to generate and differential");
+    }
+    // only running days, with commands starting with a day or all, prints reports
+    let runs_days = args
+        .first()
+        .is_none_or(|command| *command == "all" || command.parse::<u8>().is_ok());
+    if format.is_some() && !runs_days {
+        exit_with_error("--format only applies to running days");
+    }
+    if let Some(trace) = trace {
+        if !runs_days {
+            exit_with_error("--trace only applies to running days");
+        }
+        start_trace(&trace);
+    }
+    let format = format.unwrap_or(Format::Text);
+    if let Some(dir) = inputs {
+        if input.is_some() || format != Format::Text {
+            exit_with_error("--inputs cannot be combined with --input nor --format json");
+        }
+        let succeeded = match args.as_slice() {
+            [day] if *day != "all" => run_inputs(parse_day(day), None, Path::new(&dir)),
+            [day, part] => run_inputs(parse_day(day), Some(parse_part(part)), Path::new(&dir)),
+            _ => exit_with_error("--inputs only applies to running a day"),
+        };
+        process::exit(if succeeded { 0 } else { 1 });
+    }
+
+    let succeeded = match args.as_slice() {
+        ["all"] if input.is_none() => run_all(jobs.unwrap_or(1), format),
+        ["verify"] if input.is_none() => verify(),
+        ["accept", day] => accept(parse_day(day), None, input.as_ref()),
+        ["accept", day, part] => accept(parse_day(day), Some(parse_part(part)), input.as_ref()),
+        ["time", "all"] if input.is_none() => time(DAYS.iter().collect(), runs, None),
+        ["time", day] => time(vec![parse_day(day)], runs, input.as_ref()),
+        ["new", day] if input.is_none() => new_day(day),
+        ["fetch", day] if input.is_none() => fetch_input(day),
+        ["encrypt"] if input.is_none() => migrate_inputs(true),
+        ["decrypt"] if input.is_none() => migrate_inputs(false),
+        ["examples", day] if input.is_none() => examples(parse_day(day)),
+        ["watch", day] => match &input {
+            None => watch::watch(parse_day(day).number, None),
+            Some(InputArg::Path(path)) => watch::watch(parse_day(day).number, Some(path)),
+            Some(InputArg::Stdin) => exit_with_error("watch cannot read the input from stdin"),
+        },
+        ["step", day] => match &input {
+            Some(InputArg::Stdin) => exit_with_error("step reads the keys from stdin"),
+            input => step(parse_day(day), input.as_ref()),
+        },
+        ["generate", day] if input.is_none() => {
+            generate_input(parse_day(day), seed.unwrap_or(0), size.unwrap_or(100))
+        }
+        ["differential", day] if input.is_none() => {
+            differential(parse_day(day), seeds.unwrap_or(100), size.unwrap_or(20))
+        }
+        [day] => run_day(parse_day(day), None, input.as_ref(), format),
+        [day, part] => run_day(
+            parse_day(day),
+            Some(parse_part(part)),
+            input.as_ref(),
+            format,
+        ),
+        _ => exit_with_usage(),
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn start_trace(arg: &str) {
+    if arg == "-" {
+        render::trace_to_stderr();
+    } else if let Err(error) = render::trace_to_file(Path::new(arg)) {
+        eprintln!("error: could not create the trace {}: {}", arg, error);
+        process::exit(1);
+    }
+}
+
+fn run_day(day: &Day, only_part: Option<Part>, input: Option<&InputArg>, format: Format) -> bool {
+    let input = input::load(day.number, input);
+    print_reports(&report::run(day, &selected_parts(only_part), input), format)
+}
+
+/// Solves the day for every input in `dir`, returning whether none failed
+fn run_inputs(day: &Day, only_part: Option<Part>, dir: &Path) -> bool {
+    let parts = selected_parts(only_part);
+    match batch::run_dir(day, &parts, dir) {
+        Ok(rows) if rows.is_empty() => {
+            eprintln!("error: no .txt inputs in {}", dir.display());
+            false
+        }
+        Ok(rows) => {
+            print!("{}", batch::table(&parts, &rows));
+            let failed = rows.iter().filter(|row| row.failed()).count();
+            if failed > 0 {
+                eprintln!("error: {} of {} inputs failed", failed, rows.len());
+            }
+            failed == 0
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn run_all(jobs: usize, format: Format) -> bool {
+    print_reports(&report::run_all(DAYS, jobs), format)
+}
+
+/// Prints the reports, returning whether none of them failed
+fn print_reports(reports: &[PartReport], format: Format) -> bool {
+    match format {
+        Format::Json => {
+            for report in reports {
+                println!("{}", report.to_json());
+            }
+        }
+        Format::Text => {
+            for day_reports in reports.chunk_by(|a, b| a.day == b.day) {
+                print_text(day_reports);
+            }
+        }
+    }
+    reports.iter().all(|report| !report.status.is_failure())
+}
+
+/// Prints the reports of a single day, after where its input came from
+fn print_text(reports: &[PartReport]) {
+    if let Some(first) = reports.first() {
+        if let Some(source) = &first.source {
+            println!("Input of day{}: {}", first.day, source);
+        }
+    }
+    for report in reports {
+        match report.status {
+            // the input is the same for every part, so is its error
+            Status::InputError | Status::ParseError => {
+                eprintln!("error: {}", report.error.as_deref().unwrap_or_default());
+                return;
+            }
+            Status::SolveError | Status::Panicked => eprintln!("{}", report),
+            _ => println!("{}", report),
+        }
+    }
+}
+
+fn accept(day: &Day, only_part: Option<Part>, input: Option<&InputArg>) -> bool {
+    let result = AcceptedAnswers::load(Path::new(ANSWERS_FILE))
+        .map_err(Box::<dyn Error>::from)
+        .and_then(|answers| Ok((answers, load(day, input)?)));
+    let (mut answers, parsed) = match result {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+    };
+
+    // the parts that failed are left out, the others still accepted
+    let mut failed = false;
+    for part in selected_parts(only_part) {
+        let answer = match report::try_solve(parsed.as_ref(), part) {
+            Ok(answer) => answer,
+            Err((_, error)) => {
+                eprintln!("error: day{} part {}: {}", day.number, part, error);
+                failed = true;
+                continue;
+            }
+        };
+        answers.accept(day.number, part, answer);
+        println!("Accepted day{} part {}: {}", day.number, part, answer);
+    }
+
+    if let Err(error) = answers.save(Path::new(ANSWERS_FILE)) {
+        eprintln!("error: {}", error);
+        return false;
+    }
+    !failed
+}
+
+/// Reruns every day, checking the answers against the accepted ones
+fn verify() -> bool {
+    let answers = match AcceptedAnswers::load(Path::new(ANSWERS_FILE)) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+    };
+    let mut failures = 0;
+
+    for day in DAYS {
+        let parsed = match load(day, None) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                println!("[FAILED] day{}: {}", day.number, error);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let label = format!("day{} part {}", day.number, part);
+            let answer = match report::try_solve(parsed.as_ref(), part) {
+                Ok(answer) => answer.to_string(),
+                Err((Status::Unimplemented, _)) => {
+                    println!("[TODO] {}: not implemented yet", label);
+                    continue;
+                }
+                Err((_, error)) => {
+                    println!("[FAILED] {}: {}", label, error);
+                    failures += 1;
+                    continue;
+                }
+            };
+            match answers.get(day.number, part) {
+                Some(accepted) if accepted == answer => println!("[OK] {}: {}", label, answer),
+                Some(accepted) if day.broken_parts.contains(&part) => println!(
+                    "[BROKEN] {}: {}, accepted {} (known to be broken)",
+                    label, answer, accepted
+                ),
+                Some(accepted) => {
+                    println!("[CHANGED] {}: {}, accepted {}", label, answer, accepted);
+                    failures += 1;
+                }
+                None => println!("[NEW] {}: {} (not accepted yet)", label, answer),
+            }
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("error: {} answers changed or failed", failures);
+    }
+    failures == 0
+}
+
+fn examples(day: &Day) -> bool {
+    match fixtures::check_day(day.number) {
+        Ok((count, failures)) => {
+            for failure in &failures {
+                println!("{}", failure);
+            }
+            println!(
+                "day{} examples: {} checked, {} failing",
+                day.number,
+                count,
+                failures.len()
+            );
+            failures.is_empty()
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn time(days: Vec<&Day>, runs: usize, input: Option<&InputArg>) -> bool {
+    let mut rows = Vec::new();
+    let mut failures = 0;
+
+    for day in days {
+        let measured = input::load(day.number, input)
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|input| Ok(DayTimings::measure(day, &input.text, runs)?));
+        match measured {
+            Ok(timings) => {
+                for (part, status, error) in &timings.failed {
+                    if status.is_failure() {
+                        eprintln!("error: day{} part {}: {}", day.number, part, error);
+                        failures += 1;
+                    }
+                }
+                rows.push((day.number, timings))
+            }
+            Err(error) => {
+                eprintln!("error: {}", error);
+                failures += 1;
+            }
+        }
+    }
+
+    print!("{}", timing::table(&rows, runs));
+    failures == 0
+}
+
+fn step(day: &Day, input: Option<&InputArg>) -> bool {
+    let result = input::load(day.number, input)
+        .map_err(|error| error.to_string())
+        .and_then(|input| stepper::simulation(day.number, &input.text))
+        .and_then(|simulation| {
+            stepper::run(&format!("day{}", day.number), simulation.as_ref())
+                .map_err(|error| error.to_string())
+        });
+    match result {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn new_day(arg: &str) -> bool {
+    let day = arg
+        .parse()
+        .unwrap_or_else(|_| exit_with_error(&format!("invalid day {:?}", arg)));
+
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            println!("Registered day{} in src/days/mod.rs", day);
+            true
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+fn generate_input(day: &Day, seed: u64, size: usize) -> bool {
+    match generate::input(day.number, seed, size) {
+        Some(input) => {
+            print!("{}", input);
+            true
+        }
+        None => {
+            eprintln!("error: day{} has no input generator", day.number);
+            false
+        }
+    }
+}
+
+fn differential(day: &Day, seeds: usize, max_size: usize) -> bool {
+    let Some(oracle) = oracle::find(day.number) else {
+        eprintln!(
+            "error: day{} has no brute force to compare with",
+            day.number
+        );
+        return false;
+    };
+
+    match oracle.smallest_disagreement(seeds as u64, max_size) {
+        Ok(None) => {
+            println!(
+                "day{} {}: agrees with the brute force on {} seeds of every size up to {}",
+                day.number, oracle.name, seeds, max_size
+            );
+            true
+        }
+        Ok(Some(disagreement)) => {
+            println!(
+                "day{} {}: disagrees with the brute force on {}",
+                day.number, oracle.name, disagreement
+            );
+            false
+        }
+        Err(error) => {
+            eprintln!("error: generated input does not parse: {}", error);
+            false
+        }
+    }
+}
+
+fn fetch_input(arg: &str) -> bool {
+    let day = match arg.parse() {
+        Ok(day @ 1..=25) => day,
+        _ => exit_with_error(&format!("invalid day {:?}", arg)),
+    };
+
+    match Fetcher::from_config().and_then(|fetcher| fetcher.fetch(day)) {
+        Ok(Fetched::Cached(path)) => {
+            println!("The input of day{} is already in {}", day, path.display());
+            true
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded the input of day{} to {}", day, path.display());
+            true
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            false
+        }
+    }
+}
+
+/// Encrypts the plaintext inputs in the default directories, or decrypts the
+/// encrypted ones
+fn migrate_inputs(encrypt: bool) -> bool {
+    let key = match Key::from_env() {
+        Ok(key) => key,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+    };
+    let mut migrated = 0;
+    let mut failures = 0;
+
+    for dir in input::default_dirs() {
+        for day in 1..=25 {
+            let plaintext = dir.join(input::file_name(day));
+            let result = if encrypt {
+                // new days are scaffolded with an empty input, still to fetch
+                if !fs::metadata(&plaintext).is_ok_and(|metadata| metadata.len() > 0) {
+                    continue;
+                }
+                encryption::encrypt_file(&key, &plaintext)
+            } else {
+                let encrypted = encryption::encrypted_path(&plaintext);
+                if !encrypted.exists() {
+                    continue;
+                }
+                encryption::decrypt_file(&key, &encrypted)
+            };
+            match result {
+                Ok(path) => {
+                    println!("Wrote {}", path.display());
+                    migrated += 1;
+                }
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} {} inputs, {} failed",
+        if encrypt { "Encrypted" } else { "Decrypted" },
+        migrated,
+        failures
+    );
+    failures == 0
+}
+
+fn load(day: &Day, input: Option<&InputArg>) -> Result<Box<dyn Parsed>, Box<dyn Error>> {
+    let input = input::load(day.number, input)?;
+    Ok((day.parse)(&input.text)?)
+}
+
+fn selected_parts(only_part: Option<Part>) -> Vec<Part> {
+    match only_part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// Removes `name` and the value following it from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let position = args.iter().position(|arg| arg == name)?;
+    if position + 1 >= args.len() {
+        exit_with_error(&format!("{} needs a value", name));
+    }
+    let value = args.remove(position + 1);
+    args.remove(position);
+    Some(value)
+}
+
+fn parse_day(arg: &str) -> &'static Day {
+    arg.parse()
+        .ok()
+        .and_then(days::find)
+        .unwrap_or_else(|| exit_with_error(&format!("no solution for day {:?}", arg)))
+}
+
+fn parse_part(arg: &str) -> Part {
+    Part::parse(arg)
+        .unwrap_or_else(|| exit_with_error(&format!("part must be 1 or 2, got {:?}", arg)))
+}
+
+fn parse_format(arg: &str) -> Format {
+    Format::parse(arg).unwrap_or_else(|| {
+        exit_with_error(&format!("--format must be text or json, got {:?}", arg))
+    })
+}
+
+fn parse_seed(arg: &str) -> u64 {
+    arg.parse()
+        .unwrap_or_else(|_| exit_with_error(&format!("--seed needs a number, got {:?}", arg)))
+}
+
+fn parse_count(option: &str, arg: &str) -> usize {
+    match arg.parse() {
+        Ok(count) if count > 0 => count,
+        _ => exit_with_error(&format!(
+            "{} needs a positive number, got {:?}",
+            option, arg
+        )),
+    }
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    exit_with_usage()
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(1)
 }
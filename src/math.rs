@@ -0,0 +1,193 @@
+use std::fmt;
+
+/// Error of a computation whose answer does not exist or does not fit its type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// The result is too big for the integer type it is computed in
+    Overflow,
+    ZeroModulus,
+    /// No number satisfies `x ≡ residue (mod modulus)` along with the
+    /// congruences before it
+    IncompatibleCongruence {
+        residue: i64,
+        modulus: u64,
+    },
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "result overflows"),
+            MathError::ZeroModulus => write!(f, "congruence modulo 0"),
+            MathError::IncompatibleCongruence { residue, modulus } => write!(
+                f,
+                "x ≡ {} (mod {}) is incompatible with the previous congruences",
+                residue, modulus
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// Greatest common divisor, with `gcd(0, 0) == 0`
+pub fn gcd(a: u64, b: u64) -> u64 {
+    gcd_u128(a.into(), b.into()) as u64
+}
+
+pub fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+/// Least common multiple, with `lcm(a, 0) == 0`
+pub fn lcm(a: u64, b: u64) -> Result<u64, MathError> {
+    lcm_u128(a.into(), b.into())?
+        .try_into()
+        .map_err(|_| MathError::Overflow)
+}
+
+pub fn lcm_u128(a: u128, b: u128) -> Result<u128, MathError> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    (a / gcd_u128(a, b))
+        .checked_mul(b)
+        .ok_or(MathError::Overflow)
+}
+
+/// Least common multiple of all `numbers`, 1 if there are none
+pub fn lcm_of(numbers: &[u64]) -> Result<u64, MathError> {
+    numbers
+        .iter()
+        .try_fold(1, |lcm_so_far, &n| lcm(lcm_so_far, n))
+}
+
+pub fn lcm_of_u128(numbers: &[u128]) -> Result<u128, MathError> {
+    numbers
+        .iter()
+        .try_fold(1, |lcm_so_far, &n| lcm_u128(lcm_so_far, n))
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` such that `g` is the
+/// greatest common divisor of `a` and `b`, never negative, and `a * x + b * y == g`.
+/// `|x| <= |b|` and `|y| <= |a|`, so nothing overflows short of `i128::MIN`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Chinese remainder theorem: finds the smallest `x >= 0` satisfying every
+/// congruence `x ≡ residue (mod modulus)`, returned with the modulus of all
+/// the solutions, `x + k * modulus`. The moduli need not be coprime
+pub fn crt(congruences: &[(i64, u64)]) -> Result<(u64, u64), MathError> {
+    let mut solution: (u64, u64) = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        if modulus == 0 {
+            return Err(MathError::ZeroModulus);
+        }
+        let incompatible = MathError::IncompatibleCongruence { residue, modulus };
+        let (x, m) = solution;
+
+        // x + m * k ≡ residue (mod modulus), solved for k
+        let g = gcd(m, modulus);
+        let difference = (residue as i128 - x as i128).rem_euclid(modulus.into()) as u128;
+        if !difference.is_multiple_of(g as u128) {
+            return Err(incompatible);
+        }
+        let reduced_modulus = (modulus / g) as u128;
+        let (_, inverse, _) = extended_gcd((m / g).into(), reduced_modulus as i128);
+        let inverse = inverse.rem_euclid(reduced_modulus as i128) as u128;
+        let k = (difference / g as u128 % reduced_modulus) * inverse % reduced_modulus;
+
+        let combined_modulus = lcm(m, modulus)?;
+        let x = (x as u128 + m as u128 * k) % combined_modulus as u128;
+        solution = (x as u64, combined_modulus);
+    }
+
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(54, 24));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(Ok(216), lcm(54, 24));
+        assert_eq!(Ok(0), lcm(0, 24));
+        assert_eq!(Ok(60), lcm_of(&[3, 4, 5, 6]));
+        assert_eq!(Ok(1), lcm_of(&[]));
+    }
+
+    #[test]
+    fn test_lcm_overflow() {
+        let big_primes = [4_294_967_291, 4_294_967_279, 4_294_967_231];
+
+        assert_eq!(Err(MathError::Overflow), lcm_of(&big_primes));
+        assert_eq!(
+            Ok(4_294_967_291 * 4_294_967_279 * 4_294_967_231),
+            lcm_of_u128(&big_primes.map(u128::from))
+        );
+        assert_eq!(Err(MathError::Overflow), lcm_u128(u128::MAX, u128::MAX - 1));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (0, 7), (7, 0), (17, -5)] {
+            let (g, x, y) = extended_gcd(a, b);
+
+            assert_eq!(
+                gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128,
+                g
+            );
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Ok((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // moduli sharing factors
+        assert_eq!(Ok((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(Ok((4, 5)), crt(&[(-1, 5)]));
+        assert_eq!(Ok((0, 1)), crt(&[]));
+    }
+
+    #[test]
+    fn test_crt_errors() {
+        assert_eq!(
+            Err(MathError::IncompatibleCongruence {
+                residue: 1,
+                modulus: 4
+            }),
+            crt(&[(0, 6), (1, 4)])
+        );
+        assert_eq!(Err(MathError::ZeroModulus), crt(&[(1, 0)]));
+        assert_eq!(
+            Err(MathError::Overflow),
+            crt(&[(0, 4_294_967_291), (0, 4_294_967_279), (0, 4_294_967_231)])
+        );
+    }
+}
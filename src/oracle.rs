@@ -0,0 +1,213 @@
+//! Brute force references for the days whose solutions rely on a shortcut,
+//! run against them on generated inputs to find where the two disagree.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::days::day10::PipesGraph;
+use crate::days::day6::Games;
+use crate::days::day8::Map;
+use crate::generate;
+use crate::parse::ParseError;
+use crate::report;
+
+/// Steps after which the brute force of the ghosts gives up
+const GHOST_STEPS_LIMIT: u64 = 100_000_000;
+
+/// An optimized computation of a day, checked against a slow reference
+pub struct Oracle {
+    pub day: u8,
+    /// What is checked, as named in the solution of the day
+    pub name: &'static str,
+    optimized: fn(&str) -> Result<String, ParseError>,
+    brute_force: fn(&str) -> Result<String, ParseError>,
+}
+
+pub const ORACLES: &[Oracle] = &[
+    Oracle {
+        day: 6,
+        name: "min_press_time and max_press_time",
+        optimized: |input| {
+            let press_times: Vec<(u64, u64)> = Games::parse(input)?
+                .races()
+                .map(|(time, distance)| {
+                    (
+                        Games::min_press_time(distance, time),
+                        Games::max_press_time(distance, time),
+                    )
+                })
+                .collect();
+            Ok(format!("{:?}", press_times))
+        },
+        brute_force: |input| {
+            let press_times: Vec<(u64, u64)> = Games::parse(input)?
+                .races()
+                .map(|(time, distance)| {
+                    Games::press_times_brute_force(distance, time)
+                        .expect("the records can be beaten")
+                })
+                .collect();
+            Ok(format!("{:?}", press_times))
+        },
+    },
+    Oracle {
+        day: 8,
+        name: "required_steps_for_ghosts",
+        optimized: |input| {
+            let steps = Map::parse(input)?.required_steps_for_ghosts();
+            Ok(steps.map_or_else(|error| error.to_string(), |steps| steps.to_string()))
+        },
+        brute_force: |input| {
+            let steps = Map::parse(input)?.required_steps_for_ghosts_brute_force(GHOST_STEPS_LIMIT);
+            Ok(steps.map_or_else(
+                || format!("more than {} steps", GHOST_STEPS_LIMIT),
+                |steps| steps.to_string(),
+            ))
+        },
+    },
+    Oracle {
+        day: 10,
+        name: "points_within_loop",
+        optimized: |input| Ok(PipesGraph::parse(input)?.points_within_loop().to_string()),
+        brute_force: |input| {
+            Ok(PipesGraph::parse(input)?
+                .points_within_loop_brute_force()
+                .to_string())
+        },
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Oracle> {
+    ORACLES.iter().find(|oracle| oracle.day == day)
+}
+
+/// A generated input on which the optimized computation and the brute force
+/// give different results
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub optimized: String,
+    pub brute_force: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "seed {}, size {}:", self.seed, self.size)?;
+        writeln!(f, "  optimized:   {}", self.optimized)?;
+        writeln!(f, "  brute force: {}", self.brute_force)?;
+        write!(f, "{}", self.input)
+    }
+}
+
+impl Oracle {
+    /// Results of the optimized computation and of the brute force on `input`,
+    /// a panic of either being its result
+    pub fn compare(&self, input: &str) -> Result<(String, String), ParseError> {
+        let run = |computation: fn(&str) -> Result<String, ParseError>| {
+            panic::catch_unwind(AssertUnwindSafe(|| computation(input))).unwrap_or_else(|payload| {
+                Ok(format!(
+                    "panicked: {}",
+                    report::panic_message(payload.as_ref())
+                ))
+            })
+        };
+        Ok((run(self.optimized)?, run(self.brute_force)?))
+    }
+
+    /// Compares both computations on the inputs generated from `seeds` seeds for
+    /// every size up to `max_size`, giving the shortest input they disagree on of
+    /// the smallest size, if any
+    pub fn smallest_disagreement(
+        &self,
+        seeds: u64,
+        max_size: usize,
+    ) -> Result<Option<Disagreement>, ParseError> {
+        for size in 1..=max_size {
+            let mut smallest: Option<Disagreement> = None;
+            for seed in 0..seeds {
+                let input = generate::input(self.day, seed, size)
+                    .expect("the days with an oracle have a generator");
+                if smallest
+                    .as_ref()
+                    .is_some_and(|smallest| smallest.input.len() <= input.len())
+                {
+                    continue;
+                }
+                let (optimized, brute_force) = self.compare(&input)?;
+                if optimized != brute_force {
+                    smallest = Some(Disagreement {
+                        seed,
+                        size,
+                        input,
+                        optimized,
+                        brute_force,
+                    });
+                }
+            }
+            if smallest.is_some() {
+                return Ok(smallest);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oracles_agree_on_the_examples() {
+        let examples = [
+            (6, include_str!("../examples/day6/example1.txt")),
+            (8, include_str!("../examples/day8/example3.txt")),
+            (10, include_str!("../examples/day10/example4.txt")),
+        ];
+        for (day, input) in examples {
+            let (optimized, brute_force) = find(day).unwrap().compare(input).unwrap();
+            assert_eq!(brute_force, optimized, "day{}", day);
+        }
+    }
+
+    #[test]
+    fn test_oracles_agree_on_generated_inputs() {
+        for oracle in ORACLES {
+            assert_eq!(
+                None,
+                oracle.smallest_disagreement(5, 8).unwrap(),
+                "day{} {}",
+                oracle.day,
+                oracle.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_reports_the_smallest_disagreement() {
+        // counts one point too many on grids with more than 6 columns
+        let oracle = Oracle {
+            day: 10,
+            name: "points_within_loop",
+            optimized: |input| {
+                let points = PipesGraph::parse(input)?.points_within_loop_brute_force();
+                let too_many = input.lines().next().unwrap().len() > 6;
+                Ok((points + too_many as u64).to_string())
+            },
+            brute_force: |input| {
+                Ok(PipesGraph::parse(input)?
+                    .points_within_loop_brute_force()
+                    .to_string())
+            },
+        };
+
+        let disagreement = oracle.smallest_disagreement(3, 10).unwrap().unwrap();
+
+        assert_eq!(7, disagreement.size);
+        assert_eq!(
+            disagreement.brute_force.parse::<u64>().unwrap() + 1,
+            disagreement.optimized.parse::<u64>().unwrap()
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error found while parsing the input of a day, pointing to where it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// Line of the error, starting from 1
+    pub line: usize,
+    /// Column of the error in characters, starting from 1
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// Error at the start of `found`, which must be a slice of `source`
+    pub fn new(day: u8, source: &str, found: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position_of(source, found);
+        let found = found.lines().next().unwrap_or_default();
+
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.chars().take(20).collect(),
+        }
+    }
+
+    /// Error for a `source` that ended before something that was expected
+    pub fn at_end(day: u8, source: &str, expected: impl Into<String>) -> Self {
+        Self::new(day, source, &source[source.len()..], expected)
+    }
+
+    /// Moves an error found while parsing `slice` to its position in `source`,
+    /// of which `slice` is a part
+    pub fn within(self, source: &str, slice: &str) -> Self {
+        let (line, column) = position_of(source, slice);
+
+        Self {
+            line: line + self.line - 1,
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{} input, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Helpers to pick apart the text of a day's input, reporting errors at their
+/// position in `source`. Every slice given to them must be a part of `source`
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    day: u8,
+    source: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u8, source: &'a str) -> Self {
+        Self { day, source }
+    }
+
+    pub fn error(&self, found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.source, found, expected)
+    }
+
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::at_end(self.day, self.source, expected)
+    }
+
+    /// Parses a single number, ignoring the whitespace around it
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token
+            .trim()
+            .parse()
+            .map_err(|_| self.error(token.trim_start(), "a number"))
+    }
+
+    pub fn strip_prefix(&self, slice: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        slice
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(slice, format!("{:?}", prefix)))
+    }
+
+    pub fn split_once(
+        &self,
+        slice: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        slice
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(&slice[slice.len()..], format!("{:?}", delimiter)))
+    }
+
+    /// Parses whitespace separated numbers, as in `"79 14  55 13"`
+    pub fn numbers<T: FromStr, C: FromIterator<T>>(&self, slice: &str) -> Result<C, ParseError> {
+        slice
+            .split_whitespace()
+            .map(|token| self.number(token))
+            .collect()
+    }
+
+    /// Parses a line listing numbers after a label, as in `"Time:  7  15   30"`
+    /// for the label `"Time"`
+    pub fn labelled_numbers<T: FromStr, C: FromIterator<T>>(
+        &self,
+        line: &'a str,
+        label: &str,
+    ) -> Result<C, ParseError> {
+        let numbers = self.strip_prefix(line, label)?;
+        self.numbers(self.strip_prefix(numbers, ":")?)
+    }
+
+    /// Parses a record of a key and a pair of values, as in `"AAA = (BBB, CCC)"`
+    pub fn pair_record(&self, line: &'a str) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+        let (key, values) = line
+            .split_once(" = ")
+            .ok_or_else(|| self.error(&line[line.len()..], "\" = \""))?;
+        let values = self.strip_prefix(values, "(")?;
+        let values = values
+            .strip_suffix(')')
+            .ok_or_else(|| self.error(&values[values.len()..], "')'"))?;
+        let (first, second) = values
+            .split_once(", ")
+            .ok_or_else(|| self.error(&values[values.len()..], "\", \""))?;
+        for (slice, expected) in [(key, "a key"), (first, "a value"), (second, "a value")] {
+            if slice.is_empty() {
+                return Err(self.error(slice, expected));
+            }
+        }
+
+        Ok((key, (first, second)))
+    }
+
+    /// Splits the source into blocks of lines separated by blank lines, without
+    /// the newline ending each block
+    pub fn blocks(&self) -> Vec<&'a str> {
+        let mut blocks = Vec::new();
+        let mut block: Option<(usize, usize)> = None;
+
+        for line in self.source.lines() {
+            let start = line.as_ptr() as usize - self.source.as_ptr() as usize;
+            let end = start + line.len();
+            block = match (block, line.trim().is_empty()) {
+                (None, true) => None,
+                (None, false) => Some((start, end)),
+                (Some((block_start, _)), false) => Some((block_start, end)),
+                (Some((block_start, block_end)), true) => {
+                    blocks.push(&self.source[block_start..block_end]);
+                    None
+                }
+            };
+        }
+        if let Some((block_start, block_end)) = block {
+            blocks.push(&self.source[block_start..block_end]);
+        }
+
+        blocks
+    }
+
+    /// Parses a part of the source with `parse`, moving its errors to their
+    /// position in the whole source
+    pub fn within<T>(
+        &self,
+        slice: &'a str,
+        parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse(slice).map_err(|error| error.within(self.source, slice))
+    }
+
+    /// Parses every line of the source with `parse_line`, moving its errors to
+    /// their position in the whole source
+    pub fn lines<T>(
+        &self,
+        parse_line: impl Fn(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.source
+            .lines()
+            .map(|line| self.within(line, &parse_line))
+            .collect()
+    }
+}
+
+/// Line and column, both starting from 1, at which `slice` starts inside `source`
+fn position_of(source: &str, slice: &str) -> (usize, usize) {
+    let offset = (slice.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|offset| *offset <= source.len())
+        .unwrap_or(source.len());
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_of_slice() {
+        let source = "first line\nsecond line";
+
+        assert_eq!((1, 1), position_of(source, &source[..5]));
+        assert_eq!((2, 8), position_of(source, &source[18..]));
+    }
+
+    #[test]
+    fn test_error_within_bigger_source() {
+        let source = "Game 1: 3 blue\nGame 2: x red";
+        let line = source.lines().nth(1).unwrap();
+        let token = &line[8..9];
+
+        let error = ParseError::new(2, line, token, "a number").within(source, line);
+
+        assert_eq!((2, 9), (error.line, error.column));
+        assert_eq!(
+            "day2 input, line 2, column 9: expected a number, found \"x\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_labelled_numbers() {
+        let source = "Time:      7  15   30\nDistance:  9  x  200";
+        let parser = Parser::new(6, source);
+        let mut lines = source.lines();
+
+        let times: Vec<u32> = parser
+            .labelled_numbers(lines.next().unwrap(), "Time")
+            .unwrap();
+        assert_eq!(vec![7, 15, 30], times);
+
+        let error = parser
+            .labelled_numbers::<u32, Vec<_>>(lines.next().unwrap(), "Distance")
+            .unwrap_err();
+        assert_eq!(
+            (2, 15, "a number"),
+            (error.line, error.column, error.expected.as_str())
+        );
+
+        let error = parser
+            .labelled_numbers::<u32, Vec<_>>(source, "Distance")
+            .unwrap_err();
+        assert_eq!((1, 1), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_pair_record() {
+        let source = "AAA = (BBB, CCC)\nBBB = (DDD EEE)\nCCC = (, DDD)";
+        let parser = Parser::new(8, source);
+        let lines: Vec<&str> = source.lines().collect();
+
+        assert_eq!(Ok(("AAA", ("BBB", "CCC"))), parser.pair_record(lines[0]));
+
+        let error = parser.pair_record(lines[1]).unwrap_err();
+        assert_eq!(
+            (2, 15, "\", \""),
+            (error.line, error.column, error.expected.as_str())
+        );
+
+        let error = parser.pair_record(lines[2]).unwrap_err();
+        assert_eq!(
+            (3, 8, "a value"),
+            (error.line, error.column, error.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let source = "seeds: 1 2\n\na map:\n1 2 3\n4 5 6\n\n\nb map:\n7 8 9\n";
+        let parser = Parser::new(5, source);
+
+        let blocks = parser.blocks();
+
+        assert_eq!(
+            vec!["seeds: 1 2", "a map:\n1 2 3\n4 5 6", "b map:\n7 8 9"],
+            blocks
+        );
+        let error = parser
+            .within(blocks[2], |block| {
+                Parser::new(5, block).numbers::<u32, Vec<_>>(block)
+            })
+            .unwrap_err();
+        assert_eq!((8, 1), (error.line, error.column));
+    }
+}
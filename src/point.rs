@@ -0,0 +1,186 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::grid::Position;
+
+/// Point of the plane, or vector between two points, with `y` growing
+/// downwards as in the puzzle inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Point one step away in `direction`
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// Distance moving only horizontally and vertically
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance moving diagonally as well, like a king on a chessboard
+    pub fn chebyshev_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Position of the point in a grid, if none of its coordinates is negative
+    pub fn position(self) -> Option<Position> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Direction of a step to one of the 8 points around another, north being up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Every direction, clockwise from the north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Directions that are not diagonal, clockwise from the north
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Vector of a single step in this direction
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Direction a quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Direction a quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Direction::CARDINAL.contains(&self)
+    }
+
+    /// Direction `eighths` of a turn clockwise
+    fn rotate(self, eighths: usize) -> Self {
+        let index = Direction::ALL.iter().position(|d| *d == self).unwrap();
+        Direction::ALL[(index + eighths) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(-2, 3);
+        let b = Point::new(4, 1);
+
+        assert_eq!(8, a.manhattan_distance(b));
+        assert_eq!(6, a.chebyshev_distance(b));
+        assert_eq!(Point::new(6, -2), b - a);
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::NorthWest, Direction::SouthWest.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::SouthEast, Direction::NorthWest.opposite());
+        for direction in Direction::ALL {
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+            assert_eq!(direction, direction.turn_left().turn_right());
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let point = Point::new(0, 0).step(Direction::NorthWest);
+
+        assert_eq!(Point::new(-1, -1), point);
+        assert_eq!(None, point.position());
+        assert_eq!(Some((2, 1)), Point::new(2, 1).position());
+    }
+}
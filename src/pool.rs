@@ -0,0 +1,70 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::thread;
+
+/// Runs `job` on every input using `workers` threads, returning the results
+/// in the order of the inputs whichever finished first. A job that panics
+/// gives the panic payload as its result without stopping the other jobs
+pub fn map<T, R, F>(inputs: Vec<T>, workers: usize, job: F) -> Vec<thread::Result<R>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = inputs.len();
+    let queue = Mutex::new(inputs.into_iter().enumerate());
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, count.max(1)) {
+            scope.spawn(|| loop {
+                // the lock is released before running the job
+                let next = queue.lock().unwrap().next();
+                let Some((index, input)) = next else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| job(input)));
+                results.lock().unwrap()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every job ran"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_keeps_the_order_of_the_inputs() {
+        let results = map(vec![30, 0, 20, 10], 3, |millis| {
+            thread::sleep(Duration::from_millis(millis));
+            millis * 2
+        });
+
+        let results: Vec<u64> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(vec![60, 0, 40, 20], results);
+    }
+
+    #[test]
+    fn test_survives_panicking_jobs() {
+        let results = map(vec![1, 0, 2], 2, |n| {
+            if n == 0 {
+                panic!("no zero");
+            }
+            n
+        });
+
+        assert_eq!(1, *results[0].as_ref().unwrap());
+        assert!(results[1].is_err());
+        assert_eq!(2, *results[2].as_ref().unwrap());
+        assert!(map(Vec::<u8>::new(), 4, |n| n).is_empty());
+    }
+}
@@ -0,0 +1,283 @@
+//! Drawing of annotated grids for debugging, with highlight layers and a
+//! legend, and the trace they are written to when the runner is given
+//! `--trace`.
+//!
+//! A day draws a [`Picture`] only while tracing, as in
+//!
+//! ```
+//! use aoc2023::grid::Grid;
+//! use aoc2023::point::Point;
+//! use aoc2023::render::{self, Colour, Picture};
+//!
+//! let grid = Grid::parse(0, "#..\n.#.\n", "a cell", Some).unwrap();
+//! render::trace("day0: walls", || {
+//!     Picture::new(grid.clone()).layer("walls", Colour::Red, None, [Point::new(0, 0)])
+//! });
+//! ```
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+
+    pub(crate) fn paint(self, text: &str) -> String {
+        format!("\x1b[1;{}m{}\x1b[0m", self.ansi_code(), text)
+    }
+}
+
+/// Cells of a picture to highlight, drawn with their own symbol or keeping
+/// the one of the grid
+#[derive(Debug, Clone, PartialEq)]
+struct Layer {
+    name: String,
+    colour: Colour,
+    symbol: Option<char>,
+    cells: Grid<bool>,
+}
+
+/// A grid of characters with highlight layers, the last layer added being
+/// drawn over the others
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    grid: Grid<char>,
+    layers: Vec<Layer>,
+}
+
+impl Picture {
+    pub fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a layer highlighting `points`, ignoring those outside the grid
+    pub fn layer(
+        mut self,
+        name: &str,
+        colour: Colour,
+        symbol: Option<char>,
+        points: impl IntoIterator<Item = Point>,
+    ) -> Self {
+        let mut cells = Grid::new(self.grid.width(), self.grid.height(), false);
+        for position in points.into_iter().filter_map(Point::position) {
+            if let Some(cell) = cells.get_mut(position) {
+                *cell = true;
+            }
+        }
+        self.layers.push(Layer {
+            name: name.to_owned(),
+            colour,
+            symbol,
+            cells,
+        });
+        self
+    }
+
+    /// The part of the picture at most `width` by `height` cells large around
+    /// `centre`, as centred as the edges allow, its legend counting only that
+    /// part
+    pub fn crop(&self, centre: Point, width: usize, height: usize) -> Self {
+        let width = width.min(self.grid.width());
+        let height = height.min(self.grid.height());
+        let start = |centre: i64, size: usize, full: usize| {
+            (centre - size as i64 / 2).clamp(0, (full - size) as i64) as usize
+        };
+        let origin = (
+            start(centre.x, width, self.grid.width()),
+            start(centre.y, height, self.grid.height()),
+        );
+
+        Self {
+            grid: crop_grid(&self.grid, origin, width, height),
+            layers: self
+                .layers
+                .iter()
+                .map(|layer| Layer {
+                    cells: crop_grid(&layer.cells, origin, width, height),
+                    ..layer.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Draws the grid followed by a legend of its layers, with ANSI colours or
+    /// in plain text
+    pub fn render(&self, colour: bool) -> String {
+        let mut drawn = String::new();
+        for row in 0..self.grid.height() {
+            for column in 0..self.grid.width() {
+                let position = (column, row);
+                let top = self.layers.iter().rev().find(|layer| layer.cells[position]);
+                let symbol = top
+                    .and_then(|layer| layer.symbol)
+                    .unwrap_or(self.grid[position]);
+                match top {
+                    Some(layer) if colour => {
+                        drawn.push_str(&layer.colour.paint(&symbol.to_string()))
+                    }
+                    _ => drawn.push(symbol),
+                }
+            }
+            drawn.push('\n');
+        }
+
+        for layer in &self.layers {
+            let key = layer.symbol.map_or("■".to_owned(), String::from);
+            let key = if colour {
+                layer.colour.paint(&key)
+            } else {
+                key
+            };
+            let count = layer.cells.iter().filter(|(_, marked)| **marked).count();
+            let _ = writeln!(drawn, "{} {}: {}", key, layer.name, count);
+        }
+        drawn
+    }
+}
+
+fn crop_grid<T: Copy + Default>(
+    grid: &Grid<T>,
+    (x0, y0): (usize, usize),
+    width: usize,
+    height: usize,
+) -> Grid<T> {
+    let mut cropped = Grid::new(width, height, T::default());
+    for (x, y) in cropped.positions().collect::<Vec<_>>() {
+        cropped[(x, y)] = grid[(x0 + x, y0 + y)];
+    }
+    cropped
+}
+
+/// Where the pictures of the days go while tracing
+#[derive(Debug)]
+enum Trace {
+    /// Coloured when stderr is a terminal
+    Stderr,
+    File(File),
+}
+
+static TRACE: Mutex<Option<Trace>> = Mutex::new(None);
+
+/// Traces the pictures of the days to stderr
+pub fn trace_to_stderr() {
+    *TRACE.lock().unwrap() = Some(Trace::Stderr);
+}
+
+/// Traces the pictures of the days to a new file at `path`, in plain text
+pub fn trace_to_file(path: &Path) -> io::Result<()> {
+    let file = File::create(path)?;
+    *TRACE.lock().unwrap() = Some(Trace::File(file));
+    Ok(())
+}
+
+/// Draws the picture into the trace under `title` when tracing, without even
+/// making it otherwise
+pub fn trace(title: &str, picture: impl FnOnce() -> Picture) {
+    let mut trace = TRACE.lock().unwrap();
+    let result = match trace.as_mut() {
+        None => return,
+        Some(Trace::Stderr) => {
+            let colour = io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+            write_picture(&mut io::stderr().lock(), title, &picture(), colour)
+        }
+        Some(Trace::File(file)) => write_picture(file, title, &picture(), false),
+    };
+    if let Err(error) = result {
+        eprintln!("error: could not write the trace: {}", error);
+    }
+}
+
+fn write_picture(
+    out: &mut impl Write,
+    title: &str,
+    picture: &Picture,
+    colour: bool,
+) -> io::Result<()> {
+    writeln!(out, "--- {}", title)?;
+    write!(out, "{}", picture.render(colour))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn picture() -> Picture {
+        let grid = Grid::parse(0, "F-7\n|.|\nL-J\n", "a cell", Some).unwrap();
+        Picture::new(grid)
+            .layer(
+                "loop",
+                Colour::Green,
+                None,
+                [Point::new(0, 0), Point::new(1, 0), Point::new(-1, 0)],
+            )
+            .layer("inside", Colour::Red, Some('*'), [Point::new(1, 1)])
+            .layer("start", Colour::Yellow, Some('S'), [Point::new(0, 0)])
+    }
+
+    #[test]
+    fn test_render_plain() {
+        assert_eq!(
+            "S-7\n|*|\nL-J\n■ loop: 2\n* inside: 1\nS start: 1\n",
+            picture().render(false)
+        );
+    }
+
+    #[test]
+    fn test_render_coloured() {
+        let rendered = picture().render(true);
+        let first_row = rendered.lines().next().unwrap();
+
+        assert_eq!("\x1b[1;33mS\x1b[0m\x1b[1;32m-\x1b[0m7", first_row);
+        assert!(rendered.contains("\x1b[1;31m*\x1b[0m inside: 1"));
+    }
+
+    #[test]
+    fn test_crop() {
+        let cropped = picture().crop(Point::new(0, 0), 2, 2);
+        assert_eq!(
+            "S-\n|*\n■ loop: 2\n* inside: 1\nS start: 1\n",
+            cropped.render(false)
+        );
+
+        let cropped = picture().crop(Point::new(2, 2), 2, 1);
+        assert!(cropped.render(false).starts_with("-J\n"));
+        assert_eq!(picture(), picture().crop(Point::new(1, 1), 5, 5));
+    }
+
+    #[test]
+    fn test_write_picture() {
+        let mut out = Vec::new();
+
+        write_picture(&mut out, "day10: loop", &picture(), false).unwrap();
+
+        let written = String::from_utf8(out).unwrap();
+        assert!(written.starts_with("--- day10: loop\nS-7\n"));
+    }
+}
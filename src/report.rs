@@ -0,0 +1,629 @@
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::days::Day;
+use crate::input::{self, Input, InputError};
+use crate::pool;
+use crate::solution::{Answer, Parsed, Part};
+
+/// How the runner prints its results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// A JSON object per line, one for each day and part
+    Json,
+}
+
+impl Format {
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Answered, but by a part known to give a wrong answer
+    Broken,
+    /// The part is still a `todo!()` or `unimplemented!()`
+    Unimplemented,
+    InputError,
+    ParseError,
+    /// The part has no answer for this input, like one overflowing
+    SolveError,
+    /// The part panicked for another reason than being unimplemented
+    Panicked,
+}
+
+impl Status {
+    const ALL: [Status; 7] = [
+        Status::Ok,
+        Status::Broken,
+        Status::Unimplemented,
+        Status::InputError,
+        Status::ParseError,
+        Status::SolveError,
+        Status::Panicked,
+    ];
+
+    /// Reads a status back from its name in the output
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.to_string() == name)
+    }
+
+    pub fn is_failure(self) -> bool {
+        matches!(
+            self,
+            Status::InputError | Status::ParseError | Status::SolveError | Status::Panicked
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Status::Ok => "ok",
+            Status::Broken => "broken",
+            Status::Unimplemented => "unimplemented",
+            Status::InputError => "input_error",
+            Status::ParseError => "parse_error",
+            Status::SolveError => "solve_error",
+            Status::Panicked => "panicked",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Outcome of running one part of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    /// Time taken to parse the input, shared by both parts of the day
+    pub parse_time: Option<Duration>,
+    pub solve_time: Option<Duration>,
+    pub error: Option<String>,
+    /// Where the input was read from, None when it could not be
+    pub source: Option<String>,
+}
+
+impl PartReport {
+    fn failed(day: u8, part: Part, status: Status, error: String) -> Self {
+        Self {
+            day,
+            part,
+            status,
+            answer: None,
+            parse_time: None,
+            solve_time: None,
+            error: Some(error),
+            source: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut fields = vec![
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("status", json_string(&self.status.to_string())),
+        ];
+        match self.answer {
+            Some(Answer::Unsigned(answer)) => {
+                fields.push(("answer", answer.to_string()));
+                fields.push(("type", json_string("unsigned")));
+            }
+            Some(Answer::Signed(answer)) => {
+                fields.push(("answer", answer.to_string()));
+                fields.push(("type", json_string("signed")));
+            }
+            None => {
+                fields.push(("answer", "null".to_owned()));
+                fields.push(("type", "null".to_owned()));
+            }
+        }
+        let nanos = |duration: Option<Duration>| {
+            duration.map_or("null".to_owned(), |duration| {
+                duration.as_nanos().to_string()
+            })
+        };
+        fields.push(("parse_ns", nanos(self.parse_time)));
+        fields.push(("solve_ns", nanos(self.solve_time)));
+        fields.push((
+            "error",
+            self.error.as_deref().map_or("null".to_owned(), json_string),
+        ));
+        fields.push((
+            "source",
+            self.source
+                .as_deref()
+                .map_or("null".to_owned(), json_string),
+        ));
+
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|(name, value)| format!("{}:{}", json_string(name), value))
+            .collect();
+        format!("{{{}}}", fields.join(","))
+    }
+
+    /// Reads back a report printed by [`PartReport::to_json`]
+    pub fn from_json(line: &str) -> Option<Self> {
+        let fields = json_fields(line)?;
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.as_deref())
+        };
+        // None for a malformed duration, Some(None) for a missing one
+        let nanos = |name: &str| match field(name) {
+            Some(nanos) => nanos
+                .parse()
+                .ok()
+                .map(|nanos| Some(Duration::from_nanos(nanos))),
+            None => Some(None),
+        };
+
+        let answer = match (field("answer"), field("type")) {
+            (Some(answer), Some("unsigned")) => Some(Answer::Unsigned(answer.parse().ok()?)),
+            (Some(answer), Some("signed")) => Some(Answer::Signed(answer.parse().ok()?)),
+            (None, None) => None,
+            _ => return None,
+        };
+        Some(Self {
+            day: field("day")?.parse().ok()?,
+            part: Part::parse(field("part")?)?,
+            status: Status::parse(field("status")?)?,
+            answer,
+            parse_time: nanos("parse_ns")?,
+            solve_time: nanos("solve_ns")?,
+            error: field("error").map(str::to_owned),
+            source: field("source").map(str::to_owned),
+        })
+    }
+}
+
+impl fmt::Display for PartReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.status, self.answer, &self.error) {
+            (Status::Broken, Some(answer), _) => write!(
+                f,
+                "[BROKEN] Answer to day{} part {}: {}",
+                self.day, self.part, answer
+            ),
+            (_, Some(answer), _) => {
+                write!(
+                    f,
+                    "Answer to day{} part {}: {}",
+                    self.day, self.part, answer
+                )
+            }
+            (Status::Unimplemented, None, _) => {
+                write!(
+                    f,
+                    "day{} part {} is not implemented yet",
+                    self.day, self.part
+                )
+            }
+            (_, None, error) => write!(
+                f,
+                "error: day{} part {}: {}",
+                self.day,
+                self.part,
+                error.as_deref().unwrap_or("no answer")
+            ),
+        }
+    }
+}
+
+/// Parses `input` for `day` and answers each of `parts`, timing both steps and
+/// catching a part that panics so that it only fails that part
+pub fn run(day: &Day, parts: &[Part], input: Result<Input, InputError>) -> Vec<PartReport> {
+    match parse(day, parts, input) {
+        Ok(parsed) => parts
+            .iter()
+            .map(|part| solve(day, &parsed, *part))
+            .collect(),
+        Err(reports) => reports,
+    }
+}
+
+/// Runs both parts of every day with their default input on `jobs` threads:
+/// the inputs of all days are parsed concurrently, then all their parts are
+/// solved concurrently. The reports come in the order of the days, a panic only
+/// failing the day or part it happened in
+pub fn run_all(days: &[Day], jobs: usize) -> Vec<PartReport> {
+    run_all_with(days, jobs, |day| input::load(day.number, None))
+}
+
+/// [`run_all`] with the inputs given by `load`
+fn run_all_with<L>(days: &[Day], jobs: usize, load: L) -> Vec<PartReport>
+where
+    L: Fn(&Day) -> Result<Input, InputError> + Sync,
+{
+    let parsed = pool::map(days.iter().collect(), jobs, |day: &Day| {
+        parse(day, &Part::ALL, load(day))
+    });
+    let parsed: Vec<Result<ParsedInput, Vec<PartReport>>> = days
+        .iter()
+        .zip(parsed)
+        .map(|(day, result)| {
+            result.unwrap_or_else(|payload| {
+                let message = panic_message(payload.as_ref());
+                Err(Part::ALL
+                    .iter()
+                    .map(|part| {
+                        PartReport::failed(day.number, *part, Status::Panicked, message.clone())
+                    })
+                    .collect())
+            })
+        })
+        .collect();
+
+    let solve_jobs: Vec<(&Day, &ParsedInput, Part)> = days
+        .iter()
+        .zip(&parsed)
+        .filter_map(|(day, parsed)| Some((day, parsed.as_ref().ok()?)))
+        .flat_map(|(day, parsed)| Part::ALL.map(|part| (day, parsed, part)))
+        .collect();
+    let mut solved = pool::map(solve_jobs, jobs, |(day, parsed, part)| {
+        solve(day, parsed, part)
+    })
+    .into_iter();
+
+    let mut reports = Vec::new();
+    for parsed in &parsed {
+        match parsed {
+            Ok(_) => reports.extend(
+                solved
+                    .by_ref()
+                    .take(Part::ALL.len())
+                    .map(|report| report.expect("solve catches the panics of the parts")),
+            ),
+            Err(failed) => reports.extend_from_slice(failed),
+        }
+    }
+    reports
+}
+
+/// Input of a day parsed, with the time parsing took
+struct ParsedInput {
+    parsed: Box<dyn Parsed>,
+    time: Duration,
+    source: String,
+}
+
+/// Parses the input of a day, or gives the reports of each of `parts` failing
+/// for lack of an input
+fn parse(
+    day: &Day,
+    parts: &[Part],
+    input: Result<Input, InputError>,
+) -> Result<ParsedInput, Vec<PartReport>> {
+    let failed = |status: Status, error: String| -> Vec<PartReport> {
+        parts
+            .iter()
+            .map(|part| PartReport::failed(day.number, *part, status, error.clone()))
+            .collect()
+    };
+    let input = input.map_err(|error| failed(Status::InputError, error.to_string()))?;
+    let source = input.source.to_string();
+
+    let start = Instant::now();
+    let parsed = (day.parse)(&input.text).map_err(|error| {
+        let mut reports = failed(Status::ParseError, error.to_string());
+        for report in &mut reports {
+            report.source = Some(source.clone());
+        }
+        reports
+    })?;
+    Ok(ParsedInput {
+        parsed,
+        time: start.elapsed(),
+        source,
+    })
+}
+
+fn solve(day: &Day, parsed: &ParsedInput, part: Part) -> PartReport {
+    let start = Instant::now();
+    let answer = try_solve(parsed.parsed.as_ref(), part);
+    let solve_time = start.elapsed();
+
+    match answer {
+        Ok(answer) => PartReport {
+            day: day.number,
+            part,
+            status: if day.broken_parts.contains(&part) {
+                Status::Broken
+            } else {
+                Status::Ok
+            },
+            answer: Some(answer),
+            parse_time: Some(parsed.time),
+            solve_time: Some(solve_time),
+            error: None,
+            source: Some(parsed.source.clone()),
+        },
+        Err((status, message)) => PartReport {
+            parse_time: Some(parsed.time),
+            // a panic cuts the part short, its time means nothing
+            solve_time: (status == Status::SolveError).then_some(solve_time),
+            source: Some(parsed.source.clone()),
+            ..PartReport::failed(day.number, part, status, message)
+        },
+    }
+}
+
+/// Answers `part`, or gives why it failed: [`Status::SolveError`] without an
+/// answer for the input, [`Status::Unimplemented`] for a `todo!()` and
+/// [`Status::Panicked`] for any other panic, which is caught
+pub fn try_solve(parsed: &dyn Parsed, part: Part) -> Result<Answer, (Status, String)> {
+    match panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err((Status::SolveError, error.to_string())),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            let status = if message.starts_with("not yet implemented")
+                || message.starts_with("not implemented")
+            {
+                Status::Unimplemented
+            } else {
+                Status::Panicked
+            };
+            Err((status, message))
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_owned()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// The fields of a flat JSON object of strings, numbers and nulls, as printed
+/// by [`PartReport::to_json`], a null being None
+fn json_fields(line: &str) -> Option<Vec<(String, Option<String>)>> {
+    let mut chars = line
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')?
+        .chars()
+        .peekable();
+    let mut fields = Vec::new();
+
+    while chars.peek().is_some() {
+        let key = json_string_value(&mut chars)?;
+        if chars.next()? != ':' {
+            return None;
+        }
+        let value = if chars.peek() == Some(&'"') {
+            Some(json_string_value(&mut chars)?)
+        } else {
+            let mut token = String::new();
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                token.push(c);
+            }
+            (token != "null").then_some(token)
+        };
+        fields.push((key, value));
+        if chars.next().is_some_and(|c| c != ',') {
+            return None;
+        }
+    }
+    Some(fields)
+}
+
+fn json_string_value(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+    let mut value = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let code: String = chars.take(4).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::generate;
+    use crate::input::Source;
+    use crate::solution::SolveError;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(r#""a \"b\"\n\\ \u0001""#, json_string("a \"b\"\n\\ \u{1}"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = PartReport {
+            day: 7,
+            part: Part::One,
+            status: Status::Broken,
+            answer: Some(Answer::Unsigned(6440)),
+            parse_time: Some(Duration::from_micros(3)),
+            solve_time: Some(Duration::from_nanos(250)),
+            error: None,
+            source: None,
+        };
+
+        assert_eq!(
+            r#"{"day":7,"part":1,"status":"broken","answer":6440,"type":"unsigned","parse_ns":3000,"solve_ns":250,"error":null,"source":null}"#,
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        let reports = [
+            PartReport {
+                day: 9,
+                part: Part::Two,
+                status: Status::Ok,
+                answer: Some(Answer::Signed(-3)),
+                parse_time: Some(Duration::from_nanos(12)),
+                solve_time: Some(Duration::from_nanos(250)),
+                error: None,
+                source: Some("src/bin/input9.txt".to_owned()),
+            },
+            PartReport::failed(
+                2,
+                Part::One,
+                Status::ParseError,
+                "expected \"red\", found\n\u{1}, 2".to_owned(),
+            ),
+        ];
+
+        for report in reports {
+            assert_eq!(
+                Some(&report),
+                PartReport::from_json(&report.to_json()).as_ref()
+            );
+        }
+        assert_eq!(None, PartReport::from_json(r#"{"day":1}"#));
+    }
+
+    fn stdin(text: &str) -> Input {
+        Input {
+            text: text.to_owned(),
+            source: Source::Stdin,
+        }
+    }
+
+    #[test]
+    fn test_run_reports_parse_errors() {
+        let day = days::find(2).unwrap();
+
+        let reports = run(day, &Part::ALL, Ok(stdin("Game 1: 3 purple")));
+
+        assert_eq!(2, reports.len());
+        assert!(reports
+            .iter()
+            .all(|report| report.status == Status::ParseError && report.answer.is_none()));
+        assert_eq!(
+            "error: day2 part 1: day2 input, line 1, column 11: expected a color, found \"purple\"",
+            reports[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_run_flags_broken_parts() {
+        let day = days::find(7).unwrap();
+
+        let reports = run(day, &Part::ALL, Ok(stdin("32T3K 765\n")));
+
+        assert_eq!(Status::Broken, reports[0].status);
+        assert_eq!(Status::Ok, reports[1].status);
+        assert_eq!(Some("stdin"), reports[1].source.as_deref());
+    }
+
+    /// Fails its first part with a panic and its second with a `todo!()`
+    struct Failing;
+
+    impl Parsed for Failing {
+        fn part1(&self) -> Result<Answer, SolveError> {
+            panic!("no loop");
+        }
+
+        fn part2(&self) -> Result<Answer, SolveError> {
+            todo!()
+        }
+    }
+
+    #[test]
+    fn test_try_solve_catches_panics() {
+        assert_eq!(
+            Err((Status::Panicked, "no loop".to_owned())),
+            try_solve(&Failing, Part::One)
+        );
+        assert!(matches!(
+            try_solve(&Failing, Part::Two),
+            Err((Status::Unimplemented, _))
+        ));
+    }
+
+    #[test]
+    fn test_run_all_keeps_the_order_of_the_days() {
+        let reports = run_all(&days::DAYS[..3], 4);
+
+        let order: Vec<(u8, Part)> = reports
+            .iter()
+            .map(|report| (report.day, report.part))
+            .collect();
+        assert_eq!(
+            vec![
+                (1, Part::One),
+                (1, Part::Two),
+                (2, Part::One),
+                (2, Part::Two),
+                (3, Part::One),
+                (3, Part::Two)
+            ],
+            order
+        );
+    }
+
+    #[test]
+    fn test_run_all_solves_inputs_of_real_size() {
+        // a loop of thousands of pipes, followed on the worker threads
+        let input = generate::input(10, 7, 140).unwrap();
+        let day = days::find(10).unwrap();
+
+        let reports = run_all_with(std::slice::from_ref(day), 2, |_| Ok(stdin(&input)));
+
+        assert_eq!(2, reports.len());
+        assert!(
+            reports.iter().all(|report| report.status == Status::Ok),
+            "{:?}",
+            reports
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use crate::parse::ParseError;
+use crate::solution::{Answer, Solution, SolveError};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Model = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Result<Answer, SolveError> {
+        todo!()
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<Answer, SolveError> {
+        todo!()
+    }
+}
+"#;
+
+/// Sidecar of the example created with a new day, checked once filled in
+const EXPECTED_TEMPLATE: &str = "# part1 = 0
+# part2 = 0
+";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u8),
+    AlreadyExists(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "there is no day {} in the calendar", day),
+            ScaffoldError::AlreadyExists(path) => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            ScaffoldError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// Creates the module of a new day with its placeholder input and example
+/// under `root`, the directory holding `Cargo.toml`, and registers it in
+/// `src/days/mod.rs`.
+/// Returns the files created
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let module = root.join(format!("src/days/day{}.rs", day));
+    let input = root.join(format!("src/bin/input{}.txt", day));
+    let examples = root.join(format!("examples/day{}", day));
+    let example = examples.join("example1.txt");
+    let expected = examples.join("example1.toml");
+    let days_mod = root.join("src/days/mod.rs");
+
+    for path in [&module, &input, &example, &expected] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.clone()));
+        }
+    }
+    let days_mod_content = fs::read_to_string(&days_mod)
+        .map_err(|error| ScaffoldError::Io(days_mod.clone(), error))?;
+    let days_mod_content = register(&days_mod_content, day)
+        .ok_or_else(|| ScaffoldError::AlreadyExists(days_mod.clone()))?;
+
+    create_new(&module, &TEMPLATE.replace("{day}", &day.to_string()))?;
+    create_new(&input, "")?;
+    fs::create_dir_all(&examples).map_err(|error| ScaffoldError::Io(examples.clone(), error))?;
+    create_new(&example, "")?;
+    create_new(&expected, EXPECTED_TEMPLATE)?;
+    fs::write(&days_mod, days_mod_content)
+        .map_err(|error| ScaffoldError::Io(days_mod.clone(), error))?;
+
+    Ok(vec![module, input, example, expected])
+}
+
+fn create_new(path: &Path, content: &str) -> Result<(), ScaffoldError> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|error| match error.kind() {
+            io::ErrorKind::AlreadyExists => ScaffoldError::AlreadyExists(path.to_owned()),
+            _ => ScaffoldError::Io(path.to_owned(), error),
+        })
+}
+
+/// Adds the `mod` declaration and the `DAYS` entry of a day to the content of
+/// `src/days/mod.rs`, or returns `None` if the day is already declared there
+fn register(days_mod: &str, day: u8) -> Option<String> {
+    let declaration = format!("pub mod day{};", day);
+    let mut lines: Vec<String> = days_mod.lines().map(str::to_owned).collect();
+    if lines.contains(&declaration) {
+        return None;
+    }
+
+    // declarations are kept in the same order rustfmt would give them
+    let first_mod = lines.iter().position(|line| line.starts_with("pub mod "))?;
+    let mods_count = lines[first_mod..]
+        .iter()
+        .take_while(|line| line.starts_with("pub mod "))
+        .count();
+    lines.insert(first_mod + mods_count, declaration);
+    lines[first_mod..=first_mod + mods_count]
+        .sort_by_key(|line| line.trim_end_matches(';').to_owned());
+
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))?;
+    let days_end = days_start + lines[days_start..].iter().position(|line| line == "];")?;
+    lines.insert(days_end, format!("    Day::of::<day{0}::Day{0}>(),", day));
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const DAYS_MOD: &str = "pub mod day1;
+pub mod day2;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+];
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            Some(
+                "pub mod day1;
+pub mod day11;
+pub mod day2;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day11::Day11>(),
+];
+"
+                .to_owned()
+            ),
+            register(DAYS_MOD, 11)
+        );
+        assert_eq!(None, register(DAYS_MOD, 2));
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = env::temp_dir().join("aoc2023-test-new-day");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+
+        let created = new_day(&root, 11).unwrap();
+
+        assert_eq!(4, created.len());
+        let module = fs::read_to_string(root.join("src/days/day11.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day11 {\n    const DAY: u8 = 11;"));
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("src/bin/input11.txt")).unwrap()
+        );
+        assert!(root.join("examples/day11/example1.toml").exists());
+        assert!(matches!(
+            new_day(&root, 11),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            new_day(&root, 26),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use crate::math::MathError;
+use crate::parse::ParseError;
+
+/// Answer to one part of a puzzle, whichever integer type the day computes it in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Why a part has no answer for an input it parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    Math(MathError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Math(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<MathError> for SolveError {
+    fn from(error: MathError) -> Self {
+        SolveError::Math(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn parse(input: &str) -> Option<Self> {
+        match input {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day of the calendar: parses the puzzle input once into a typed model,
+/// then answers both parts from that model, or tells why it cannot
+pub trait Solution {
+    const DAY: u8;
+
+    /// Shared between the threads solving each part
+    type Model: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError>;
+
+    fn part1(model: &Self::Model) -> Result<Answer, SolveError>;
+
+    fn part2(model: &Self::Model) -> Result<Answer, SolveError>;
+}
+
+/// Puzzle input already parsed by some [`Solution`], with the model type erased
+/// so that days can be handled uniformly
+pub trait Parsed: Send + Sync {
+    fn part1(&self) -> Result<Answer, SolveError>;
+
+    fn part2(&self) -> Result<Answer, SolveError>;
+
+    fn solve(&self, part: Part) -> Result<Answer, SolveError> {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+struct ParsedModel<S: Solution>(S::Model);
+
+impl<S: Solution> Parsed for ParsedModel<S> {
+    fn part1(&self) -> Result<Answer, SolveError> {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        S::part2(&self.0)
+    }
+}
+
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    Ok(Box::new(ParsedModel::<S>(S::parse(input)?)))
+}
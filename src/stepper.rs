@@ -0,0 +1,634 @@
+//! Steps through the simulations some days run, forward and back, drawing the
+//! state after each step in the terminal with plain ANSI escapes: the card
+//! copies cascading in day 4, the walk of `Map::required_steps` in day 8 and
+//! the search of `PipesGraph::find_loop_with_trail` around the loop in day 10.
+//!
+//! The states are recorded before stepping starts, then drawn on demand.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+
+use crate::days::day10::PipesGraph;
+use crate::days::day4::Cards;
+use crate::days::day8::Map;
+use crate::point::Point;
+use crate::render::Colour;
+
+/// Steps after which the walk of day 8 is cut short
+const WALK_LIMIT: usize = 1_000_000;
+
+const HELP: &str = "→/l next  ←/h back  ↓/↑ ±10  PgDn/PgUp ±100  Home/g first  End/G last  q quit";
+
+/// Room to draw a state in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct View {
+    pub width: usize,
+    pub height: usize,
+    pub colour: bool,
+}
+
+/// A simulation whose states can be drawn in any order
+pub trait Simulation {
+    /// Number of states, the first one being before any step
+    fn states(&self) -> usize;
+
+    /// Draws the state `index`, at most `view.height` lines long
+    fn draw(&self, index: usize, view: &View) -> String;
+}
+
+/// The simulation of `day` on `input`, for the days that have one
+pub fn simulation(day: u8, input: &str) -> Result<Box<dyn Simulation>, String> {
+    let error = |error: crate::ParseError| error.to_string();
+    match day {
+        4 => Ok(Box::new(CardCopies::new(
+            Cards::parse(input).map_err(error)?,
+        ))),
+        8 => Ok(Box::new(Walk::new(Map::parse(input).map_err(error)?)?)),
+        10 => Ok(Box::new(LoopSearch::new(
+            PipesGraph::parse(input).map_err(error)?,
+        ))),
+        _ => Err(format!(
+            "no simulation to step through for day{}, only for days 4, 8 and 10",
+            day
+        )),
+    }
+}
+
+fn paint(colour: Colour, text: &str, view: &View) -> String {
+    if view.colour {
+        colour.paint(text)
+    } else {
+        text.to_owned()
+    }
+}
+
+/// The range of at most `size` items out of `len` around `centre`, as centred
+/// as the ends allow
+fn window(centre: usize, size: usize, len: usize) -> std::ops::Range<usize> {
+    let size = size.min(len);
+    let start = centre.saturating_sub(size / 2).min(len - size);
+    start..start + size
+}
+
+/// Day 4: each card in turn wins copies of the next ones, once per instance
+/// of itself
+struct CardCopies {
+    cards: Cards,
+    history: Vec<Vec<u32>>,
+}
+
+impl CardCopies {
+    fn new(cards: Cards) -> Self {
+        let history = cards.instances_after_each_card();
+        Self { cards, history }
+    }
+}
+
+impl Simulation for CardCopies {
+    fn states(&self) -> usize {
+        self.history.len()
+    }
+
+    fn draw(&self, index: usize, view: &View) -> String {
+        let instances = &self.history[index];
+        let previous = &self.history[index.saturating_sub(1)];
+        let total: u32 = instances.iter().sum();
+        let mut drawn = String::new();
+
+        // state i follows the scratching of card i, the i-th one
+        let current = index.checked_sub(1);
+        match current {
+            None => {
+                let _ = writeln!(drawn, "one instance of each card, none scratched yet");
+            }
+            Some(i) => {
+                let matches = self.cards.cards[i].winning_numbers_count();
+                let won = (i + 2..=i + 1 + matches).filter(|id| *id <= instances.len());
+                let won: Vec<String> = won.map(|id| id.to_string()).collect();
+                let _ = writeln!(
+                    drawn,
+                    "card {} has {} matching numbers: its {} instances win a copy of {} each",
+                    i + 1,
+                    matches,
+                    instances[i],
+                    if won.is_empty() {
+                        "no card".to_owned()
+                    } else {
+                        format!("cards {}", won.join(", "))
+                    }
+                );
+            }
+        }
+        let _ = writeln!(drawn, "{} instances in total", total);
+
+        let rows = window(
+            current.unwrap_or(0),
+            view.height.saturating_sub(2),
+            instances.len(),
+        );
+        for i in rows {
+            let line = format!(
+                "{} Card {:>3}: {:>2} matches  {:>8} instances",
+                if current == Some(i) { '>' } else { ' ' },
+                i + 1,
+                self.cards.cards[i].winning_numbers_count(),
+                instances[i]
+            );
+            let gained = instances[i] - previous[i];
+            let line = if current == Some(i) {
+                paint(Colour::Yellow, &line, view)
+            } else if gained > 0 {
+                paint(Colour::Green, &format!("{} (+{})", line, gained), view)
+            } else {
+                line
+            };
+            let _ = writeln!(drawn, "{}", line);
+        }
+        drawn
+    }
+}
+
+/// Day 8: the walk from AAA to ZZZ following the instructions
+struct Walk {
+    map: Map,
+    instructions: Vec<char>,
+    walked: Vec<(String, usize)>,
+}
+
+impl Walk {
+    fn new(map: Map) -> Result<Self, String> {
+        if map.node("AAA").is_none() {
+            return Err("no node AAA to walk from".to_owned());
+        }
+        let mut walked = Vec::new();
+        for (location, index) in map.walk("AAA").take(WALK_LIMIT) {
+            walked.push((location.to_owned(), index));
+            if location == "ZZZ" {
+                break;
+            }
+        }
+        Ok(Self {
+            instructions: map.instructions().chars().collect(),
+            map,
+            walked,
+        })
+    }
+}
+
+impl Simulation for Walk {
+    fn states(&self) -> usize {
+        self.walked.len()
+    }
+
+    fn draw(&self, index: usize, view: &View) -> String {
+        let (location, instruction) = &self.walked[index];
+        let mut drawn = String::new();
+        let _ = writeln!(drawn, "at {} after {} steps", location, index);
+
+        let shown = window(
+            *instruction,
+            view.width.saturating_sub(30).max(1),
+            self.instructions.len(),
+        );
+        let instructions: String = shown
+            .clone()
+            .map(|i| {
+                let letter = self.instructions[i].to_string();
+                if i == *instruction {
+                    paint(Colour::Yellow, &format!("[{}]", letter), view)
+                } else {
+                    letter
+                }
+            })
+            .collect();
+        let _ = writeln!(
+            drawn,
+            "instruction {} of {}: {}{}{}",
+            instruction + 1,
+            self.instructions.len(),
+            if shown.start > 0 { "…" } else { "" },
+            instructions,
+            if shown.end < self.instructions.len() {
+                "…"
+            } else {
+                ""
+            }
+        );
+
+        match self.map.node(location) {
+            _ if location == "ZZZ" => {
+                let _ = writeln!(
+                    drawn,
+                    "{}",
+                    paint(
+                        Colour::Green,
+                        &format!("reached ZZZ in {} steps", index),
+                        view
+                    )
+                );
+            }
+            Some((left, right)) => {
+                let (turn, next) = match self.instructions[*instruction] {
+                    'L' => ("left", left),
+                    _ => ("right", right),
+                };
+                let _ = writeln!(
+                    drawn,
+                    "{} = ({}, {}), going {} to {}",
+                    location, left, right, turn, next
+                );
+            }
+            None => {
+                let _ = writeln!(
+                    drawn,
+                    "{}",
+                    paint(Colour::Red, &format!("{} is not a node", location), view)
+                );
+            }
+        }
+        if index + 1 == self.walked.len() && location != "ZZZ" && self.map.node(location).is_some()
+        {
+            let _ = writeln!(drawn, "stopped after {} steps without reaching ZZZ", index);
+        }
+
+        let recent = window(index, view.height.saturating_sub(5), index + 1);
+        let _ = writeln!(drawn, "path:");
+        for (i, (location, instruction)) in self.walked[recent.clone()].iter().enumerate() {
+            let _ = writeln!(
+                drawn,
+                "  {:>7} {} {}",
+                recent.start + i,
+                location,
+                self.instructions[*instruction]
+            );
+        }
+        drawn
+    }
+}
+
+/// Day 10: the search around the loop from the start
+struct LoopSearch {
+    graph: PipesGraph,
+    trail: Vec<Point>,
+    loop_length: usize,
+}
+
+impl LoopSearch {
+    fn new(graph: PipesGraph) -> Self {
+        let mut trail = Vec::new();
+        let loop_length = graph.find_loop_with_trail(&mut trail).len();
+        Self {
+            graph,
+            trail,
+            loop_length,
+        }
+    }
+}
+
+impl Simulation for LoopSearch {
+    fn states(&self) -> usize {
+        self.trail.len()
+    }
+
+    fn draw(&self, index: usize, view: &View) -> String {
+        let current = self.trail[index];
+        let mut drawn = String::new();
+        let _ = writeln!(
+            drawn,
+            "at ({}, {}) after {} steps{}",
+            current.x,
+            current.y,
+            index,
+            if index + 1 == self.trail.len() {
+                format!(
+                    ", loop of {} pipes, the farthest {} steps away",
+                    self.loop_length,
+                    self.loop_length / 2
+                )
+            } else {
+                String::new()
+            }
+        );
+
+        let visited: HashSet<Point> = self.trail[..=index].iter().copied().collect();
+        let picture = self.graph.pipes_picture(&visited, true).layer(
+            "current",
+            Colour::Yellow,
+            Some('@'),
+            [current],
+        );
+        // below the first line, the legend takes a line per layer
+        let height = view.height.saturating_sub(3).max(1);
+        drawn.push_str(
+            &picture
+                .crop(current, view.width, height)
+                .render(view.colour),
+        );
+        drawn
+    }
+}
+
+/// What a key asks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Forward(usize),
+    Back(usize),
+    First,
+    Last,
+    Quit,
+}
+
+/// The keys in bytes read from the terminal, ignoring unknown ones
+pub fn parse_keys(bytes: &[u8]) -> Vec<Key> {
+    let mut keys = Vec::new();
+    let mut rest = bytes;
+    while let Some((&byte, after)) = rest.split_first() {
+        rest = after;
+        let key = match byte {
+            b'l' | b'n' | b' ' | b'\r' => Key::Forward(1),
+            b'h' | b'p' | b'b' | 127 => Key::Back(1),
+            b'j' => Key::Forward(10),
+            b'k' => Key::Back(10),
+            b'g' => Key::First,
+            b'G' => Key::Last,
+            // Ctrl-C and Ctrl-D too
+            b'q' | 3 | 4 => Key::Quit,
+            0x1b => {
+                let sequences: [(&[u8], Key); 12] = [
+                    (b"[C", Key::Forward(1)),
+                    (b"OC", Key::Forward(1)),
+                    (b"[D", Key::Back(1)),
+                    (b"OD", Key::Back(1)),
+                    (b"[B", Key::Forward(10)),
+                    (b"[A", Key::Back(10)),
+                    (b"[6~", Key::Forward(100)),
+                    (b"[5~", Key::Back(100)),
+                    (b"[H", Key::First),
+                    (b"[1~", Key::First),
+                    (b"[F", Key::Last),
+                    (b"[4~", Key::Last),
+                ];
+                match sequences
+                    .iter()
+                    .find(|(sequence, _)| rest.starts_with(sequence))
+                {
+                    Some((sequence, key)) => {
+                        rest = &rest[sequence.len()..];
+                        *key
+                    }
+                    None if rest.is_empty() => Key::Quit,
+                    // another escape sequence, skipped up to its final letter or ~
+                    None => {
+                        let end = rest
+                            .iter()
+                            .skip(1)
+                            .position(|byte| byte.is_ascii_alphabetic() || *byte == b'~')
+                            .map_or(rest.len(), |end| end + 2);
+                        rest = &rest[end.min(rest.len())..];
+                        continue;
+                    }
+                }
+            }
+            _ => continue,
+        };
+        keys.push(key);
+    }
+    keys
+}
+
+/// Position among the states of a simulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stepper {
+    pub index: usize,
+    pub states: usize,
+}
+
+impl Stepper {
+    pub fn new(states: usize) -> Self {
+        Self { index: 0, states }
+    }
+
+    /// Moves as the key asks, staying within the states. False for Quit.
+    pub fn apply(&mut self, key: Key) -> bool {
+        let last = self.states.saturating_sub(1);
+        self.index = match key {
+            Key::Forward(steps) => (self.index + steps).min(last),
+            Key::Back(steps) => self.index.saturating_sub(steps),
+            Key::First => 0,
+            Key::Last => last,
+            Key::Quit => return false,
+        };
+        true
+    }
+
+    fn status(&self, title: &str) -> String {
+        format!("{}: state {} of {}", title, self.index + 1, self.states)
+    }
+}
+
+/// Steps through the simulation under `title` in the terminal, or when stdin
+/// is not a terminal, draws the states it reads keys for one after the other
+pub fn run(title: &str, simulation: &dyn Simulation) -> io::Result<()> {
+    let mut stepper = Stepper::new(simulation.states());
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    if !stdin.is_terminal() {
+        let mut bytes = Vec::new();
+        stdin.lock().read_to_end(&mut bytes)?;
+        let view = View {
+            width: 80,
+            height: 40,
+            colour: false,
+        };
+        writeln!(stdout, "--- {}", stepper.status(title))?;
+        write!(stdout, "{}", simulation.draw(0, &view))?;
+        for key in parse_keys(&bytes) {
+            if !stepper.apply(key) {
+                break;
+            }
+            writeln!(stdout, "--- {}", stepper.status(title))?;
+            write!(stdout, "{}", simulation.draw(stepper.index, &view))?;
+        }
+        return Ok(());
+    }
+
+    let terminal = RawTerminal::enter()?;
+    let mut buffer = [0; 64];
+    loop {
+        let (height, width) = terminal.size();
+        let view = View {
+            width,
+            // the status and help lines
+            height: height.saturating_sub(2),
+            colour: std::env::var_os("NO_COLOR").is_none(),
+        };
+        let drawn = simulation.draw(stepper.index, &view);
+        let frame = format!(
+            "\x1b[H\x1b[2J{}\n{}{}",
+            paint(Colour::Cyan, &stepper.status(title), &view),
+            drawn,
+            HELP
+        );
+        stdout.write_all(frame.as_bytes())?;
+        stdout.flush()?;
+
+        let read = stdin.lock().read(&mut buffer)?;
+        if read == 0 {
+            return Ok(());
+        }
+        for key in parse_keys(&buffer[..read]) {
+            if !stepper.apply(key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The terminal reading keys as they are typed, without echoing them, on the
+/// alternate screen until dropped
+struct RawTerminal {
+    saved: String,
+}
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&[
+            "-icanon", "-echo", "-isig", "-icrnl", "min", "1", "time", "0",
+        ])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        Ok(Self {
+            saved: saved.trim().to_owned(),
+        })
+    }
+
+    /// Rows and columns of the terminal
+    fn size(&self) -> (usize, usize) {
+        stty(&["size"])
+            .ok()
+            .and_then(|size| {
+                let (rows, columns) = size.trim().split_once(' ')?;
+                Some((rows.parse().ok()?, columns.parse().ok()?))
+            })
+            // pseudo-terminals may not have a size
+            .filter(|(rows, columns)| *rows > 0 && *columns > 0)
+            .unwrap_or((24, 80))
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[self.saved.as_str()]);
+    }
+}
+
+/// Runs stty on the terminal of stdin, giving its output
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "stty {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEW: View = View {
+        width: 80,
+        height: 20,
+        colour: false,
+    };
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            vec![
+                Key::Forward(1),
+                Key::Back(1),
+                Key::Forward(100),
+                Key::First,
+                Key::First,
+                Key::Last,
+                Key::Forward(1),
+                Key::Quit
+            ],
+            // x and the sequence of Ctrl-Delete are unknown
+            parse_keys(b"l\x1b[D\x1b[6~x\x1b[Hg\x1b[4~\x1b[3;5~ \x1b")
+        );
+    }
+
+    #[test]
+    fn test_stepper_stays_within_the_states() {
+        let mut stepper = Stepper::new(5);
+
+        assert!(stepper.apply(Key::Back(1)));
+        assert_eq!(0, stepper.index);
+        stepper.apply(Key::Forward(3));
+        assert_eq!(3, stepper.index);
+        stepper.apply(Key::Forward(100));
+        assert_eq!(4, stepper.index);
+        stepper.apply(Key::First);
+        assert_eq!(0, stepper.index);
+        stepper.apply(Key::Last);
+        assert_eq!(4, stepper.index);
+        assert!(!stepper.apply(Key::Quit));
+    }
+
+    #[test]
+    fn test_card_copies() {
+        let simulation = simulation(4, include_str!("../examples/day4/example1.txt")).unwrap();
+
+        assert_eq!(7, simulation.states());
+        let first = simulation.draw(1, &VIEW);
+        assert!(first.starts_with(
+            "card 1 has 4 matching numbers: its 1 instances win a copy of cards 2, 3, 4, 5 each\n"
+        ));
+        assert!(first.contains("  Card   2:  2 matches         2 instances (+1)\n"));
+        assert!(simulation
+            .draw(6, &VIEW)
+            .contains("\n30 instances in total\n"));
+    }
+
+    #[test]
+    fn test_walk() {
+        let simulation = simulation(8, include_str!("../examples/day8/example2.txt")).unwrap();
+
+        assert_eq!(7, simulation.states());
+        let drawn = simulation.draw(1, &VIEW);
+        assert!(drawn.starts_with("at BBB after 1 steps\ninstruction 2 of 3: L[L]R\n"));
+        assert!(drawn.contains("BBB = (AAA, ZZZ), going left to AAA\n"));
+        assert!(simulation.draw(6, &VIEW).contains("reached ZZZ in 6 steps"));
+    }
+
+    #[test]
+    fn test_loop_search() {
+        let simulation = simulation(10, include_str!("../examples/day10/example1.txt")).unwrap();
+
+        assert_eq!(8, simulation.states());
+        let drawn = simulation.draw(2, &VIEW);
+        assert!(drawn.contains("@ current: 1\n"), "{}", drawn);
+        assert!(simulation
+            .draw(7, &VIEW)
+            .contains("loop of 8 pipes, the farthest 4 steps away"));
+    }
+
+    #[test]
+    fn test_other_days_have_no_simulation() {
+        assert!(simulation(1, "1abc2").is_err());
+        assert!(simulation(8, "L\n\nBBB = (BBB, BBB)\n").is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::days::Day;
+use crate::parse::ParseError;
+use crate::report::{self, Status};
+use crate::solution::Part;
+
+/// Durations of every run of a day, with parsing measured apart from solving
+#[derive(Debug, Default)]
+pub struct DayTimings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+    /// Parts that failed or are not implemented, with their error, no longer
+    /// timed after that
+    pub failed: Vec<(Part, Status, String)>,
+}
+
+impl DayTimings {
+    /// Parses and solves `input` `runs` times, timing each step, a part that
+    /// panics or has no answer being left out of the next runs
+    pub fn measure(day: &Day, input: &str, runs: usize) -> Result<Self, ParseError> {
+        let mut timings = Self::default();
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            let parsed = (day.parse)(black_box(input))?;
+            timings.parse.push(start.elapsed());
+
+            for part in Part::ALL {
+                if timings.failed.iter().any(|(failed, _, _)| *failed == part) {
+                    continue;
+                }
+                let start = Instant::now();
+                let answer = report::try_solve(parsed.as_ref(), part);
+                let elapsed = start.elapsed();
+                if let Err((status, error)) = black_box(answer) {
+                    timings.failed.push((part, status, error));
+                    continue;
+                }
+                match part {
+                    Part::One => timings.part1.push(elapsed),
+                    Part::Two => timings.part2.push(elapsed),
+                }
+            }
+        }
+
+        Ok(timings)
+    }
+}
+
+/// Shortest and median duration among some runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Stats {
+    pub fn of(durations: &[Duration]) -> Option<Self> {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        Some(Stats {
+            min: *sorted.first()?,
+            median: sorted[sorted.len() / 2],
+        })
+    }
+}
+
+/// Renders a row per day with the time taken by parsing and by each part
+pub fn table(rows: &[(u8, DayTimings)], runs: usize) -> String {
+    let cell = |durations: &[Duration]| match Stats::of(durations) {
+        Some(stats) if runs > 1 => format!(
+            "{} / {}",
+            format_duration(stats.min),
+            format_duration(stats.median)
+        ),
+        Some(stats) => format_duration(stats.min),
+        None => "-".to_owned(),
+    };
+
+    let mut lines = vec![[
+        "day".to_owned(),
+        "parse".to_owned(),
+        "part 1".to_owned(),
+        "part 2".to_owned(),
+    ]];
+    for (day, timings) in rows {
+        let part_cell = |part: Part, durations: &[Duration]| match timings
+            .failed
+            .iter()
+            .find(|(failed, _, _)| *failed == part)
+        {
+            Some((_, status, _)) => status.to_string(),
+            None => cell(durations),
+        };
+        lines.push([
+            format!("day{}", day),
+            cell(&timings.parse),
+            part_cell(Part::One, &timings.part1),
+            part_cell(Part::Two, &timings.part2),
+        ]);
+    }
+
+    let mut table = String::new();
+    if runs > 1 {
+        table.push_str(&format!("min / median over {} runs\n", runs));
+    }
+    table.push_str(&align(&lines));
+    table
+}
+
+/// Lays out the lines in columns as wide as their widest cell
+pub(crate) fn align<L: AsRef<[String]>>(lines: &[L]) -> String {
+    let columns = lines
+        .iter()
+        .map(|line| line.as_ref().len())
+        .max()
+        .unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            lines
+                .iter()
+                .filter_map(|line| line.as_ref().get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    for line in lines {
+        let cells: Vec<String> = line
+            .as_ref()
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        table.push_str(cells.join(" | ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_stats() {
+        let durations = [5, 1, 3, 2, 100].map(Duration::from_millis);
+
+        assert_eq!(
+            Some(Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3)
+            }),
+            Stats::of(&durations)
+        );
+        assert_eq!(None, Stats::of(&[]));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.5µs", format_duration(Duration::from_nanos(1_500)));
+        assert_eq!("12.0ms", format_duration(Duration::from_millis(12)));
+        assert_eq!("2.50s", format_duration(Duration::from_millis(2_500)));
+    }
+
+    #[test]
+    fn test_table() {
+        let timings = DayTimings {
+            parse: vec![Duration::from_micros(20), Duration::from_micros(10)],
+            part1: vec![Duration::from_millis(1), Duration::from_millis(3)],
+            part2: vec![Duration::from_secs(2), Duration::from_secs(1)],
+            failed: Vec::new(),
+        };
+
+        assert_eq!(
+            "min / median over 2 runs
+day   | parse           | part 1        | part 2
+day10 | 10.0µs / 20.0µs | 1.0ms / 3.0ms | 1.00s / 2.00s
+",
+            table(&[(10, timings)], 2)
+        );
+    }
+
+    #[test]
+    fn test_measure_skips_failing_parts() {
+        // part 1 of day8 panics without a walk from AAA to ZZZ
+        let day = days::find(8).unwrap();
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n";
+
+        let timings = DayTimings::measure(day, input, 3).unwrap();
+
+        assert!(timings.part1.is_empty());
+        assert_eq!(3, timings.part2.len());
+        assert_eq!(Part::One, timings.failed[0].0);
+        assert_eq!(Status::Panicked, timings.failed[0].1);
+        assert_eq!(1, timings.failed.len());
+        assert!(table(&[(8, timings)], 1).contains("| panicked |"));
+    }
+}
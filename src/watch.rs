@@ -0,0 +1,296 @@
+//! Reruns a day whenever its source, input or examples change, checking its
+//! examples and comparing its answers with those of the previous run.
+//!
+//! The day is rebuilt with cargo, in its own target directory so that the
+//! rebuilt binary never replaces the running one nor waits on other builds.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::fixtures::EXAMPLES_DIR;
+use crate::input;
+use crate::report::PartReport;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Modification times of the watched files, None for a missing one
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    /// Files created, removed or modified since `previous`
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut paths: Vec<&PathBuf> = self.0.keys().chain(previous.0.keys()).collect();
+        paths.sort();
+        paths.dedup();
+        paths
+            .into_iter()
+            .filter(|path| {
+                self.0.get(*path).copied().flatten() != previous.0.get(*path).copied().flatten()
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// The files a day is rerun for, along with the directories whose files are
+/// all watched
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watched {
+    files: Vec<PathBuf>,
+    dirs: Vec<PathBuf>,
+}
+
+impl Watched {
+    /// The source of the day, its input and its examples
+    pub fn day(day: u8, input: Option<&Path>) -> Self {
+        let mut files = vec![Path::new(MANIFEST_DIR).join(format!("src/days/day{}.rs", day))];
+        match input {
+            Some(input) => files.push(input.to_path_buf()),
+            None => files.extend(input::default_paths(day)),
+        }
+        Self {
+            files,
+            dirs: vec![Path::new(EXAMPLES_DIR).join(format!("day{}", day))],
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        let modified = |path: &Path| {
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+        let mut times = BTreeMap::new();
+        for file in &self.files {
+            times.insert(file.clone(), modified(file));
+        }
+        for dir in &self.dirs {
+            for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+                times.insert(entry.path(), modified(&entry.path()));
+            }
+        }
+        Snapshot(times)
+    }
+}
+
+/// Lines giving the answer or failure of each part, compared with the
+/// previous run when there was one
+pub fn answer_diff(previous: Option<&[PartReport]>, current: &[PartReport]) -> Vec<String> {
+    current
+        .iter()
+        .map(|report| {
+            let now = outcome(report);
+            let before = previous.and_then(|previous| {
+                previous
+                    .iter()
+                    .find(|previous| previous.part == report.part)
+                    .map(outcome)
+            });
+            match before {
+                Some(before) if before != now => {
+                    format!("part {}: {} (was {})", report.part, now, before)
+                }
+                _ => format!("part {}: {}", report.part, now),
+            }
+        })
+        .collect()
+}
+
+fn outcome(report: &PartReport) -> String {
+    match (report.answer, &report.error) {
+        (Some(answer), _) => answer.to_string(),
+        (None, error) => format!("{} {}", report.status, error.as_deref().unwrap_or_default())
+            .trim_end()
+            .to_owned(),
+    }
+}
+
+/// Runs the day, then again on every change of its watched files, until
+/// interrupted. `input` replaces its default input.
+pub fn watch(day: u8, input: Option<&Path>) -> ! {
+    let watched = Watched::day(day, input);
+    let mut snapshot = watched.snapshot();
+    let mut previous: Option<Vec<PartReport>> = None;
+    let mut reason = "started".to_owned();
+
+    for run in 1.. {
+        println!("--- day{}, run {}, {}", day, run, reason);
+        if let Some(reports) = rerun(day, input, previous.as_deref()) {
+            previous = Some(reports);
+        }
+
+        let changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            let changes = watched.snapshot().changes(&snapshot);
+            if !changes.is_empty() {
+                // editors often write a file in several steps
+                thread::sleep(POLL_INTERVAL);
+                snapshot = watched.snapshot();
+                break changes;
+            }
+        };
+        let names: Vec<String> = changes
+            .iter()
+            .map(|path| {
+                path.strip_prefix(MANIFEST_DIR)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        reason = format!("{} changed", names.join(", "));
+    }
+    unreachable!("the runs never end")
+}
+
+/// Rebuilds the binary, checks the examples of the day and solves its input,
+/// printing how it went. Gives the reports of the parts unless the build
+/// failed.
+fn rerun(
+    day: u8,
+    input: Option<&Path>,
+    previous: Option<&[PartReport]>,
+) -> Option<Vec<PartReport>> {
+    let target_dir = Path::new(MANIFEST_DIR).join("target/watch");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let build = Command::new(cargo)
+        .current_dir(MANIFEST_DIR)
+        .args([
+            "build",
+            "--release",
+            "--quiet",
+            "--bin",
+            "aoc2023",
+            "--target-dir",
+        ])
+        .arg(&target_dir)
+        .output();
+    match build {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => {
+            println!("build failed:");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            for line in stderr.lines().filter(|line| line.starts_with("error")) {
+                println!("  {}", line);
+            }
+            return None;
+        }
+        Err(error) => {
+            println!("could not run cargo: {}", error);
+            return None;
+        }
+    }
+
+    let binary = target_dir.join("release/aoc2023");
+    let examples = Command::new(&binary)
+        .args(["examples", &day.to_string()])
+        .output();
+    print_output("examples", examples);
+
+    let mut solve = Command::new(&binary);
+    solve.args([&day.to_string(), "--format", "json"]);
+    if let Some(input) = input {
+        solve.arg("--input").arg(input);
+    }
+    let reports: Vec<PartReport> = match solve.output() {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(PartReport::from_json)
+            .collect(),
+        Err(error) => {
+            println!("could not run the day: {}", error);
+            return None;
+        }
+    };
+    for line in answer_diff(previous, &reports) {
+        println!("{}", line);
+    }
+    Some(reports)
+}
+
+/// Prints the output of a command, or why it could not run
+fn print_output(name: &str, output: io::Result<Output>) {
+    match output {
+        Ok(output) => {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            print!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(error) => println!("could not run the {}: {}", name, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Status;
+    use crate::{Answer, Part};
+    use std::fs::File;
+
+    fn report(part: Part, answer: Option<u64>) -> PartReport {
+        PartReport {
+            day: 1,
+            part,
+            status: if answer.is_some() {
+                Status::Ok
+            } else {
+                Status::Panicked
+            },
+            answer: answer.map(Answer::Unsigned),
+            parse_time: None,
+            solve_time: None,
+            error: answer.is_none().then(|| "oops".to_owned()),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = env::temp_dir().join("aoc2023-test-snapshot-changes");
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("day1.rs");
+        let example = dir.join("example1.txt");
+        fs::write(&source, "").unwrap();
+        let _ = fs::remove_file(&example);
+        let watched = Watched {
+            files: vec![source.clone(), dir.join("missing.txt")],
+            dirs: vec![dir.clone()],
+        };
+
+        let before = watched.snapshot();
+        assert!(watched.snapshot().changes(&before).is_empty());
+
+        fs::write(&example, "1abc2").unwrap();
+        File::options()
+            .write(true)
+            .open(&source)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH)
+            .unwrap();
+
+        assert_eq!(vec![source, example], watched.snapshot().changes(&before));
+    }
+
+    #[test]
+    fn test_answer_diff() {
+        let previous = [report(Part::One, Some(142)), report(Part::Two, Some(281))];
+        let current = [report(Part::One, Some(142)), report(Part::Two, None)];
+
+        assert_eq!(
+            vec!["part 1: 142", "part 2: panicked oops (was 281)"],
+            answer_diff(Some(&previous), &current)
+        );
+        assert_eq!(
+            vec!["part 1: 142", "part 2: 281"],
+            answer_diff(None, &previous)
+        );
+    }
+}